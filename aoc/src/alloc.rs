@@ -0,0 +1,158 @@
+//! Counting allocator, to see what a solution costs in memory besides time.
+//!
+//! A binary installs it with `#[global_allocator] static ALLOCATOR: Counting = Counting;` and
+//! wraps the code to measure in [`measure`]. The counts are kept per thread, so that days running
+//! at the same time on other threads do not add to them.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Allocations made by some code: how many, how many bytes in total, and the most bytes it had
+/// allocated at once on top of what was already allocated when it started
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+struct Counters {
+    allocations: Cell<u64>,
+    bytes: Cell<u64>,
+    // signed, as memory allocated by another thread can be freed on this one
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Global allocator that counts the allocations of every thread before handing them to the system
+/// allocator
+pub struct Counting;
+
+fn allocated(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    let _ = COUNTERS.try_with(|c| {
+        c.allocations.set(c.allocations.get() + 1);
+        c.bytes.set(c.bytes.get() + size as u64);
+        c.live.set(c.live.get() + size as i64);
+        c.peak.set(c.peak.get().max(c.live.get()));
+    });
+}
+
+fn freed(size: usize) {
+    let _ = COUNTERS.try_with(|c| c.live.set(c.live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// whether `Counting` is the global allocator, otherwise every `Usage` is zero
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// run `f`, counting what it allocates on this thread
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let (allocations, bytes, live, outer_peak) = COUNTERS.with(|c| {
+        let start = (
+            c.allocations.get(),
+            c.bytes.get(),
+            c.live.get(),
+            c.peak.get(),
+        );
+        c.peak.set(c.live.get());
+        start
+    });
+    let result = f();
+    let usage = COUNTERS.with(|c| {
+        let peak = c.peak.get();
+        // keep the peak of a measurement that this one is part of
+        c.peak.set(peak.max(outer_peak));
+        Usage {
+            allocations: c.allocations.get() - allocations,
+            bytes: c.bytes.get() - bytes,
+            peak: (peak - live).max(0) as u64,
+        }
+    });
+    (result, usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        let (kept, usage) = measure(|| {
+            drop(vec![0_u8; 4000]);
+            vec![0_u8; 1000]
+        });
+        assert!(is_installed());
+        assert_eq!(
+            usage,
+            Usage {
+                allocations: 2,
+                bytes: 5000,
+                peak: 4000
+            }
+        );
+
+        let ((_, inner), outer) = measure(|| {
+            let small = vec![0_u8; 100];
+            let inner = measure(|| vec![0_u8; 300]);
+            drop(small);
+            inner
+        });
+        assert_eq!(inner.peak, 300);
+        assert_eq!(outer.peak, 400);
+        assert_eq!(outer.allocations, 2);
+        drop(kept);
+    }
+}
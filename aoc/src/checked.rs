@@ -0,0 +1,246 @@
+//! Overflow-checked arithmetic for solvers.
+//!
+//! Arithmetic on [`Checked`] behaves like the plain integer by default: it panics on overflow in
+//! debug builds and silently wraps in release builds. With checking enabled, every operation
+//! panics on overflow, in release builds too. Checking is enabled by building with the `checked`
+//! feature (e.g. `cargo test --workspace --features aoc/checked`), or at runtime with the
+//! `AOC_CHECKED=1` environment variable or [`set_enabled`]. The `try_*` methods always check and
+//! report overflow as an error instead.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+/// environment variable that enables checking at runtime when set to `1` or `true`
+pub const ENV_VAR: &str = "AOC_CHECKED";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// turn overflow checking on or off for every `Checked` value, e.g. from a runner flag
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    static FROM_ENV: OnceLock<bool> = OnceLock::new();
+    cfg!(feature = "checked")
+        || ENABLED.load(Ordering::Relaxed)
+        || *FROM_ENV.get_or_init(|| matches!(std::env::var(ENV_VAR).as_deref(), Ok("1" | "true")))
+}
+
+/// Arithmetic operation that overflowed (or divided by zero)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub lhs: String,
+    pub op: &'static str,
+    pub rhs: String,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow in `{} {} {}`",
+            self.lhs, self.op, self.rhs
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// primitive integers with checked operations
+pub trait Int:
+    Copy
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {$(
+        impl Int for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+            fn checked_div(self, rhs: Self) -> Option<Self> { <$t>::checked_div(self, rhs) }
+            fn checked_rem(self, rhs: Self) -> Option<Self> { <$t>::checked_rem(self, rhs) }
+            fn checked_pow(self, exp: u32) -> Option<Self> { <$t>::checked_pow(self, exp) }
+            fn pow(self, exp: u32) -> Self { <$t>::pow(self, exp) }
+        }
+    )*};
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Integer whose arithmetic can be checked for overflow, see the module documentation
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checked<T>(pub T);
+
+impl<T: Int> Checked<T> {
+    pub fn pow(self, exp: u32) -> Self {
+        if is_enabled() {
+            self.try_pow(exp).unwrap_or_else(|e| panic!("{e}"))
+        } else {
+            Checked(self.0.pow(exp))
+        }
+    }
+
+    pub fn try_pow(self, exp: u32) -> Result<Self, Overflow> {
+        self.0
+            .checked_pow(exp)
+            .map(Checked)
+            .ok_or_else(|| Overflow {
+                lhs: self.0.to_string(),
+                op: "^",
+                rhs: exp.to_string(),
+            })
+    }
+}
+
+macro_rules! impl_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $try_method:ident, $checked:ident, $symbol:literal) => {
+        impl<T: Int> Checked<T> {
+            pub fn $try_method(self, rhs: impl Into<Checked<T>>) -> Result<Self, Overflow> {
+                let rhs = rhs.into();
+                self.0.$checked(rhs.0).map(Checked).ok_or_else(|| Overflow {
+                    lhs: self.0.to_string(),
+                    op: $symbol,
+                    rhs: rhs.0.to_string(),
+                })
+            }
+        }
+
+        impl<T: Int> $op for Checked<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                if is_enabled() {
+                    self.$try_method(rhs).unwrap_or_else(|e| panic!("{e}"))
+                } else {
+                    Checked(self.0.$method(rhs.0))
+                }
+            }
+        }
+
+        impl<T: Int> $op<T> for Checked<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: T) -> Self {
+                self.$method(Checked(rhs))
+            }
+        }
+
+        impl<T: Int> $op_assign for Checked<T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+
+        impl<T: Int> $op_assign<T> for Checked<T> {
+            fn $method_assign(&mut self, rhs: T) {
+                *self = self.$method(Checked(rhs));
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign, try_add, checked_add, "+");
+impl_op!(Sub, sub, SubAssign, sub_assign, try_sub, checked_sub, "-");
+impl_op!(Mul, mul, MulAssign, mul_assign, try_mul, checked_mul, "*");
+impl_op!(Div, div, DivAssign, div_assign, try_div, checked_div, "/");
+impl_op!(Rem, rem, RemAssign, rem_assign, try_rem, checked_rem, "%");
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self {
+        Checked(value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Int + Default> Sum for Checked<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Checked(T::default()), |acc, x| acc + x)
+    }
+}
+
+impl<T: Int + Default> Sum<T> for Checked<T> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Checked(T::default()), |acc, x| acc + x)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Checked(40_u64);
+        assert_eq!(a + 2, Checked(42));
+        assert_eq!(a - Checked(2), Checked(38));
+        assert_eq!(a * 2024, Checked(80960));
+        assert_eq!(a / 3, Checked(13));
+        assert_eq!(a % 3, Checked(1));
+        assert_eq!(Checked(10_u64).pow(3), Checked(1000));
+
+        let mut b = Checked(-5_isize);
+        b *= 3;
+        b += Checked(1);
+        assert_eq!(b, Checked(-14));
+        assert_eq!([1_u32, 2, 3].into_iter().sum::<Checked<u32>>(), Checked(6));
+    }
+
+    #[test]
+    fn test_try() {
+        assert_eq!(Checked(u64::MAX - 1).try_add(1_u64), Ok(Checked(u64::MAX)));
+        let err = Checked(u64::MAX / 2).try_mul(2024_u64).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "arithmetic overflow in `9223372036854775807 * 2024`"
+        );
+        assert!(Checked(1_u8).try_sub(2_u8).is_err());
+        assert!(Checked(1_i32).try_div(0).is_err());
+        assert!(Checked(10_u32).try_pow(10).is_err());
+    }
+
+    /// enables checking until dropped, also when the test panics
+    pub(crate) struct EnabledGuard;
+
+    impl EnabledGuard {
+        pub(crate) fn new() -> Self {
+            set_enabled(true);
+            Self
+        }
+    }
+
+    impl Drop for EnabledGuard {
+        fn drop(&mut self) {
+            set_enabled(false);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "arithmetic overflow in `200 + 100`")]
+    fn test_overflow_panics_when_enabled() {
+        let _enabled = EnabledGuard::new();
+        let _ = Checked(200_u8) + 100;
+    }
+}
@@ -0,0 +1,282 @@
+//! Small parser combinators for instruction-stream puzzles.
+//!
+//! A parser is anything implementing [`Parser`], which includes every closure of the form
+//! `Fn(&str) -> Option<(O, &str)>`: on success it returns the parsed value and the remaining
+//! input, on failure `None`. Combinators take parsers and return new ones:
+//!
+//! ```
+//! use aoc::combinator::{delimited, literal, number, scan, separated_pair};
+//!
+//! let mul = delimited(
+//!     literal("mul("),
+//!     separated_pair(number::<u32>(), literal(","), number::<u32>()),
+//!     literal(")"),
+//! );
+//! let products = scan("xmul(2,4)%&mul[3,7]!mul(11,8)", mul)
+//!     .map(|(a, b)| a * b)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(products, vec![8, 88]);
+//! ```
+
+use std::str::FromStr;
+
+pub trait Parser<'a, O> {
+    fn parse(&self, input: &'a str) -> Option<(O, &'a str)>;
+}
+
+impl<'a, O, F> Parser<'a, O> for F
+where
+    F: Fn(&'a str) -> Option<(O, &'a str)>,
+{
+    fn parse(&self, input: &'a str) -> Option<(O, &'a str)> {
+        self(input)
+    }
+}
+
+/// match `tag` exactly
+pub fn literal<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        input
+            .strip_prefix(tag)
+            .map(|rest| (&input[..tag.len()], rest))
+    }
+}
+
+/// match one or more ascii digits
+pub fn digits<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len());
+        (end > 0).then(|| input.split_at(end))
+    }
+}
+
+/// match an integer with an optional leading `-`, and parse it into `T`
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let (digits, rest) = digits().parse(&input[sign..])?;
+        let number = input[..sign + digits.len()].parse().ok()?;
+        Some((number, rest))
+    }
+}
+
+/// skip any leading whitespace, always succeeds
+pub fn whitespace<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let rest = input.trim_start();
+        Some((&input[..input.len() - rest.len()], rest))
+    }
+}
+
+/// transform the output of `parser` with `f`
+pub fn map<'a, O, U>(parser: impl Parser<'a, O>, f: impl Fn(O) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| parser.parse(input).map(|(out, rest)| (f(out), rest))
+}
+
+/// replace the output of `parser` with (a clone of) `value`
+pub fn value<'a, O, V: Clone>(value: V, parser: impl Parser<'a, O>) -> impl Parser<'a, V> {
+    move |input: &'a str| parser.parse(input).map(|(_, rest)| (value.clone(), rest))
+}
+
+/// try `first`, and fall back to `second` on the same input when it fails
+pub fn alt<'a, O>(first: impl Parser<'a, O>, second: impl Parser<'a, O>) -> impl Parser<'a, O> {
+    move |input: &'a str| first.parse(input).or_else(|| second.parse(input))
+}
+
+/// run `first` and then `second`, returning both outputs
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Some(((a, b), rest))
+    }
+}
+
+/// run `first`, `sep` and `second`, returning the outputs of `first` and `second`
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    sep: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (_, rest) = sep.parse(rest)?;
+        let (b, rest) = second.parse(rest)?;
+        Some(((a, b), rest))
+    }
+}
+
+/// run `prefix` and then `parser`, returning only the output of `parser`
+pub fn preceded<'a, P, O>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, O>,
+) -> impl Parser<'a, O> {
+    move |input: &'a str| {
+        let (_, rest) = prefix.parse(input)?;
+        parser.parse(rest)
+    }
+}
+
+/// run `parser` and then `suffix`, returning only the output of `parser`
+pub fn terminated<'a, O, S>(
+    parser: impl Parser<'a, O>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, O> {
+    move |input: &'a str| {
+        let (out, rest) = parser.parse(input)?;
+        let (_, rest) = suffix.parse(rest)?;
+        Some((out, rest))
+    }
+}
+
+/// run `open`, `parser` and `close`, returning only the output of `parser`
+pub fn delimited<'a, L, O, R>(
+    open: impl Parser<'a, L>,
+    parser: impl Parser<'a, O>,
+    close: impl Parser<'a, R>,
+) -> impl Parser<'a, O> {
+    terminated(preceded(open, parser), close)
+}
+
+/// one or more `item`s separated by `sep`
+pub fn separated<'a, O, S>(
+    item: impl Parser<'a, O>,
+    sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<O>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Some((next, after)) = sep.parse(rest).and_then(|(_, r)| item.parse(r)) {
+            items.push(next);
+            rest = after;
+        }
+        Some((items, rest))
+    }
+}
+
+/// Iterate over every match of `parser` in `input`, skipping over anything that does not match.
+///
+/// After a match, scanning continues directly behind it; otherwise it moves on by one character.
+pub fn scan<'a, O, P>(input: &'a str, parser: P) -> impl Iterator<Item = O> + 'a
+where
+    P: Parser<'a, O> + 'a,
+{
+    let mut rest = input;
+    std::iter::from_fn(move || loop {
+        if rest.is_empty() {
+            return None;
+        }
+        if let Some((out, after)) = parser.parse(rest) {
+            // guard against parsers that succeed without consuming anything
+            rest = if after.len() < rest.len() {
+                after
+            } else {
+                skip_char(rest)
+            };
+            return Some(out);
+        }
+        rest = skip_char(rest);
+    })
+}
+
+fn skip_char(input: &str) -> &str {
+    let mut chars = input.chars();
+    chars.next();
+    chars.as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Instruction {
+        Mul(u32, u32),
+        Do,
+        Dont,
+    }
+
+    #[test]
+    fn test_literal() {
+        assert_eq!(literal("mul(").parse("mul(2,4)"), Some(("mul(", "2,4)")));
+        assert_eq!(literal("mul(").parse("mul[2,4]"), None);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>().parse("123,4"), Some((123, ",4")));
+        assert_eq!(number::<i32>().parse("-12)"), Some((-12, ")")));
+        assert_eq!(number::<u32>().parse("-12)"), None);
+        assert_eq!(number::<u32>().parse("x1"), None);
+        assert_eq!(number::<u8>().parse("300"), None);
+    }
+
+    #[test]
+    fn test_alt_value() {
+        let toggle = alt(
+            value(Instruction::Do, literal("do()")),
+            value(Instruction::Dont, literal("don't()")),
+        );
+        assert_eq!(toggle.parse("don't()_"), Some((Instruction::Dont, "_")));
+        assert_eq!(toggle.parse("do()?"), Some((Instruction::Do, "?")));
+        assert_eq!(toggle.parse("do_not"), None);
+    }
+
+    #[test]
+    fn test_separated() {
+        let list = separated(number::<u64>(), literal(","));
+        assert_eq!(list.parse("75,47,61|"), Some((vec![75, 47, 61], "|")));
+        // a trailing separator is left unconsumed
+        assert_eq!(list.parse("1,2,"), Some((vec![1, 2], ",")));
+        assert_eq!(list.parse(",1"), None);
+    }
+
+    #[test]
+    fn test_preceded_whitespace() {
+        let register = preceded(pair(literal("Register A:"), whitespace()), number::<u64>());
+        assert_eq!(register.parse("Register A: 729\n"), Some((729, "\n")));
+    }
+
+    #[test]
+    fn test_scan() {
+        let mul = map(
+            delimited(
+                literal("mul("),
+                separated_pair(number(), literal(","), number()),
+                literal(")"),
+            ),
+            |(a, b)| Instruction::Mul(a, b),
+        );
+        let toggle = alt(
+            value(Instruction::Do, literal("do()")),
+            value(Instruction::Dont, literal("don't()")),
+        );
+        let instruction = alt(mul, toggle);
+
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let instructions = scan(input, instruction).collect::<Vec<_>>();
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Mul(2, 4),
+                Instruction::Dont,
+                Instruction::Mul(5, 5),
+                Instruction::Mul(11, 8),
+                Instruction::Do,
+                Instruction::Mul(8, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_empty_match() {
+        // a parser that never consumes input must not loop forever
+        let nothing = whitespace();
+        assert_eq!(scan("ab", nothing).count(), 2);
+    }
+}
@@ -0,0 +1,181 @@
+//! Allocation-free digit manipulation on `u64`. Zero has a single digit, `0`.
+
+/// number of decimal digits of `n`
+pub fn num_digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// `10^num_digits(n)`, or `None` if that does not fit in a `u64`
+fn digit_factor(n: u64) -> Option<u64> {
+    10_u64.checked_pow(num_digits(n))
+}
+
+/// Decimal concatenation, `concat(12, 345) == Some(12345)`, or `None` on overflow
+pub fn concat(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(digit_factor(b)?)?.checked_add(b)
+}
+
+/// Split into the leading `at` decimal digits and the remaining ones.
+///
+/// ```
+/// use aoc::digits::split_at_digit;
+///
+/// assert_eq!(split_at_digit(253000, 3), (253, 0));
+/// assert_eq!(split_at_digit(1234, 0), (0, 1234));
+/// ```
+pub fn split_at_digit(n: u64, at: u32) -> (u64, u64) {
+    let rest = num_digits(n).saturating_sub(at);
+    // all 20 digits of a large `u64` stay in the rest, as `10^20` does not fit
+    match 10_u64.checked_pow(rest) {
+        Some(factor) => (n / factor, n % factor),
+        None => (0, n),
+    }
+}
+
+/// decimal digits of `n` in reverse order, `reverse_digits(1230) == Some(321)`, or `None` on overflow
+pub fn reverse_digits(n: u64) -> Option<u64> {
+    from_digits(digits(n, 10).rev(), 10)
+}
+
+/// Digits of `n` in `base`, most significant first
+#[derive(Debug, Clone)]
+pub struct Digits {
+    value: u64,
+    base: u64,
+    // place value of the most significant digit not yet returned
+    high: u64,
+    remaining: usize,
+}
+
+/// Iterate over the digits of `n` in `base` (at least 2), most significant first.
+///
+/// ```
+/// use aoc::digits::digits;
+///
+/// assert_eq!(digits(1024, 10).collect::<Vec<_>>(), vec![1, 0, 2, 4]);
+/// assert_eq!(digits(6, 2).collect::<Vec<_>>(), vec![1, 1, 0]);
+/// ```
+pub fn digits(n: u64, base: u64) -> Digits {
+    assert!(base >= 2, "base must be at least 2");
+    let mut high = 1;
+    let mut remaining = 1;
+    while n / high >= base {
+        high *= base;
+        remaining += 1;
+    }
+    Digits {
+        value: n,
+        base,
+        high,
+        remaining,
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let digit = self.value / self.high % self.base;
+        self.high /= self.base;
+        self.remaining -= 1;
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    fn next_back(&mut self) -> Option<u64> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let digit = self.value % self.base;
+        self.value /= self.base;
+        self.high /= self.base;
+        self.remaining -= 1;
+        Some(digit)
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+/// Number from its digits in `base`, most significant first, or `None` on overflow
+pub fn from_digits(digits: impl IntoIterator<Item = u64>, base: u64) -> Option<u64> {
+    digits.into_iter().try_fold(0_u64, |acc, digit| {
+        acc.checked_mul(base)?.checked_add(digit)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_digits() {
+        assert_eq!(num_digits(0), 1);
+        assert_eq!(num_digits(9), 1);
+        assert_eq!(num_digits(10), 2);
+        assert_eq!(num_digits(2024), 4);
+        assert_eq!(num_digits(u64::MAX), 20);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(12, 345), Some(12345));
+        assert_eq!(concat(15, 6), Some(156));
+        assert_eq!(concat(7, 0), Some(70));
+        assert_eq!(concat(0, 7), Some(7));
+        assert_eq!(concat(1, u64::MAX), None);
+        assert_eq!(concat(u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_split_at_digit() {
+        assert_eq!(split_at_digit(1000, 2), (10, 0));
+        assert_eq!(split_at_digit(2024, 2), (20, 24));
+        assert_eq!(split_at_digit(99, 5), (99, 0));
+        assert_eq!(split_at_digit(0, 1), (0, 0));
+        assert_eq!(split_at_digit(u64::MAX, 0), (0, u64::MAX));
+        assert_eq!(split_at_digit(u64::MAX, 1), (1, 8446744073709551615));
+        assert_eq!(split_at_digit(u64::MAX, 20), (u64::MAX, 0));
+    }
+
+    #[test]
+    fn test_reverse_digits() {
+        assert_eq!(reverse_digits(0), Some(0));
+        assert_eq!(reverse_digits(1230), Some(321));
+        assert_eq!(reverse_digits(7), Some(7));
+        assert_eq!(reverse_digits(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(0, 10).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(digits(255, 16).collect::<Vec<_>>(), vec![15, 15]);
+        assert_eq!(digits(u64::MAX, 10).len(), 20);
+        assert_eq!(digits(u64::MAX, 2).len(), 64);
+        assert_eq!(digits(1234, 10).rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+
+        // taking digits from both ends
+        let mut digits = digits(12345, 10);
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.next_back(), Some(5));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next_back(), Some(4));
+        assert_eq!(digits.next(), Some(3));
+        assert_eq!(digits.next(), None);
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(from_digits([1, 0, 2, 4], 10), Some(1024));
+        assert_eq!(from_digits(digits(u64::MAX, 7), 7), Some(u64::MAX));
+        assert_eq!(from_digits([], 10), Some(0));
+        assert_eq!(from_digits([1; 21], 10), None);
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint set over the dense indices `0..len`, with path compression and union by rank
+#[derive(Debug, Clone, Default)]
+pub struct DenseDisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DenseDisjointSet {
+    /// `len` singleton components
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// add a new singleton component and return its index
+    pub fn push(&mut self) -> usize {
+        let idx = self.parent.len();
+        self.parent.push(idx);
+        self.rank.push(0);
+        self.size.push(1);
+        self.count += 1;
+        idx
+    }
+
+    /// representative of the component containing `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // point every node on the path directly at the root
+        let mut node = x;
+        while self.parent[node] != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+        root
+    }
+
+    /// merge the components of `a` and `b`, returning false if they already were the same
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// number of elements in the component containing `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// indices grouped by component, in order of their smallest index
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let group = *groups.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[group].push(x);
+        }
+        components
+    }
+}
+
+/// Disjoint set over arbitrary hashable keys, which are added on first use
+#[derive(Debug, Clone)]
+pub struct DisjointSet<K> {
+    index: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: DenseDisjointSet,
+}
+
+impl<K> Default for DisjointSet<K> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            keys: Vec::new(),
+            sets: DenseDisjointSet::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> DisjointSet<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// add `key` as a singleton component if it is not in the set yet, returning its index
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&idx) = self.index.get(&key) {
+            return idx;
+        }
+        let idx = self.sets.push();
+        self.index.insert(key.clone(), idx);
+        self.keys.push(key);
+        idx
+    }
+
+    /// representative key of the component containing `key`
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let idx = *self.index.get(key)?;
+        let root = self.sets.find(idx);
+        Some(&self.keys[root])
+    }
+
+    /// merge the components of `a` and `b` (adding either if needed), returning false if they
+    /// already were the same
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let a = self.insert(a);
+        let b = self.insert(b);
+        self.sets.union(a, b)
+    }
+
+    pub fn same(&mut self, a: &K, b: &K) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.same(a, b),
+            _ => false,
+        }
+    }
+
+    /// number of keys in the component containing `key`, or 0 when `key` is unknown
+    pub fn size(&mut self, key: &K) -> usize {
+        self.index.get(key).map_or(0, |&idx| self.sets.size(idx))
+    }
+
+    /// number of components
+    pub fn count(&self) -> usize {
+        self.sets.count()
+    }
+
+    /// keys grouped by component, in insertion order
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|idx| self.keys[idx].clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense() {
+        let mut sets = DenseDisjointSet::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sets = DenseDisjointSet::new(100);
+        for i in 1..100 {
+            sets.union(i - 1, i);
+        }
+        let root = sets.find(99);
+        assert!((0..100).all(|i| sets.find(i) == root));
+        // after finding every element once, all of them point directly at the root
+        assert!(sets.parent.iter().all(|&parent| parent == root));
+        assert_eq!(sets.size(0), 100);
+        assert_eq!(sets.count(), 1);
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut sets = DisjointSet::new();
+        sets.union("kh", "tc");
+        sets.union("qp", "kh");
+        sets.union("de", "cg");
+        sets.insert("yn");
+
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.count(), 3);
+        assert!(sets.same(&"tc", &"qp"));
+        assert!(!sets.same(&"tc", &"de"));
+        assert!(!sets.same(&"tc", &"unknown"));
+        assert_eq!(sets.size(&"kh"), 3);
+        assert_eq!(sets.size(&"unknown"), 0);
+        assert_eq!(sets.find(&"yn"), Some(&"yn"));
+        assert_eq!(
+            sets.components(),
+            vec![vec!["kh", "tc", "qp"], vec!["de", "cg"], vec!["yn"]]
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::{Display, Write};
+use std::ops::Bound::{Excluded, Unbounded};
+
+use crate::parse::ParseError;
+
+/// Undirected graph stored as adjacency sets.
+///
+/// Nodes and neighbours are kept in sorted order, so iteration (and therefore every result
+/// below) is deterministic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N> {
+    adjacency: BTreeMap<N, BTreeSet<N>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            adjacency: BTreeMap::new(),
+        }
+    }
+}
+
+impl<'a> Graph<&'a str> {
+    /// Parse one `a-b` edge per line, such as the `kh-tc` lines of a network map.
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut graph = Graph::new();
+        for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::new(line, "an edge `a-b`").at_line(i + 1))?;
+            graph.add_edge(a.trim(), b.trim());
+        }
+        Ok(graph)
+    }
+}
+
+impl<N: Ord + Clone> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.adjacency.entry(node).or_default();
+    }
+
+    /// add an undirected edge; a self-loop only adds its node, as a node is never its own
+    /// neighbour
+    pub fn add_edge(&mut self, a: N, b: N) {
+        if a == b {
+            self.add_node(a);
+            return;
+        }
+        self.adjacency
+            .entry(a.clone())
+            .or_default()
+            .insert(b.clone());
+        self.adjacency.entry(b).or_default().insert(a);
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.adjacency.keys()
+    }
+
+    /// every edge once, with the smaller node first
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.adjacency.iter().flat_map(|(a, neighbours)| {
+            neighbours
+                .range((Excluded(a), Unbounded))
+                .map(move |b| (a, b))
+        })
+    }
+
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.adjacency.get(node).into_iter().flatten()
+    }
+
+    pub fn has_edge(&self, a: &N, b: &N) -> bool {
+        self.adjacency.get(a).is_some_and(|n| n.contains(b))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+
+    /// connected components, each as a sorted set of nodes
+    pub fn components(&self) -> Vec<BTreeSet<N>> {
+        let mut seen = BTreeSet::new();
+        let mut components = Vec::new();
+        for node in self.nodes() {
+            if seen.contains(node) {
+                continue;
+            }
+            let component = self.distances(node).into_keys().collect::<BTreeSet<_>>();
+            seen.extend(component.iter().cloned());
+            components.push(component);
+        }
+        components
+    }
+
+    /// all sets of three mutually connected nodes, each sorted
+    pub fn triangles(&self) -> Vec<[N; 3]> {
+        let mut triangles = Vec::new();
+        for (a, b) in self.edges() {
+            // only look at c > b > a, so each triangle is found once
+            for c in self.adjacency[b].range((Excluded(b), Unbounded)) {
+                if self.has_edge(a, c) {
+                    triangles.push([a.clone(), b.clone(), c.clone()]);
+                }
+            }
+        }
+        triangles
+    }
+
+    /// all maximal cliques, using Bron–Kerbosch with pivoting
+    pub fn maximal_cliques(&self) -> Vec<BTreeSet<N>> {
+        let mut cliques = Vec::new();
+        let candidates = self.adjacency.keys().cloned().collect();
+        self.bron_kerbosch(BTreeSet::new(), candidates, BTreeSet::new(), &mut cliques);
+        cliques
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: BTreeSet<N>,
+        mut candidates: BTreeSet<N>,
+        mut excluded: BTreeSet<N>,
+        cliques: &mut Vec<BTreeSet<N>>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                cliques.push(clique);
+            }
+            return;
+        }
+        // pick the pivot with the most candidate neighbours, those can be skipped here
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|node| self.adjacency[*node].intersection(&candidates).count())
+            .cloned()
+            .unwrap();
+        let pivot_neighbours = &self.adjacency[&pivot];
+        let to_visit = candidates
+            .difference(pivot_neighbours)
+            .cloned()
+            .collect::<Vec<_>>();
+        for node in to_visit {
+            let neighbours = &self.adjacency[&node];
+            let mut next_clique = clique.clone();
+            next_clique.insert(node.clone());
+            self.bron_kerbosch(
+                next_clique,
+                candidates.intersection(neighbours).cloned().collect(),
+                excluded.intersection(neighbours).cloned().collect(),
+                cliques,
+            );
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+
+    /// the largest clique, preferring the one with the smallest nodes on ties
+    pub fn max_clique(&self) -> Option<BTreeSet<N>> {
+        self.maximal_cliques()
+            .into_iter()
+            .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+    }
+
+    /// number of edges on the shortest path from `from` to every reachable node
+    pub fn distances(&self, from: &N) -> BTreeMap<N, usize> {
+        let mut distances = BTreeMap::new();
+        if !self.adjacency.contains_key(from) {
+            return distances;
+        }
+        distances.insert(from.clone(), 0);
+        let mut queue = VecDeque::from([from.clone()]);
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            for next in &self.adjacency[&node] {
+                if !distances.contains_key(next) {
+                    distances.insert(next.clone(), distance + 1);
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+        distances
+    }
+
+    /// a shortest path from `from` to `to`, including both ends, or `None` when either is not in
+    /// the graph
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<Vec<N>> {
+        if !self.adjacency.contains_key(from) || !self.adjacency.contains_key(to) {
+            return None;
+        }
+        let mut came_from: BTreeMap<N, N> = BTreeMap::new();
+        let mut queue = VecDeque::from([from.clone()]);
+        came_from.insert(from.clone(), from.clone());
+        while let Some(node) = queue.pop_front() {
+            if node == *to {
+                // reconstruct path
+                let mut path = vec![node.clone()];
+                let mut current = node;
+                while current != *from {
+                    current = came_from[&current].clone();
+                    path.push(current.clone());
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in self.neighbours(&node) {
+                if !came_from.contains_key(next) {
+                    came_from.insert(next.clone(), node.clone());
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<N: Ord + Clone + Display> Graph<N> {
+    /// Graphviz representation, e.g. for `dot -Tsvg`
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph {\n");
+        for (node, neighbours) in &self.adjacency {
+            if neighbours.is_empty() {
+                writeln!(dot, "    \"{node}\";").unwrap();
+            }
+        }
+        for (a, b) in self.edges() {
+            writeln!(dot, "    \"{a}\" -- \"{b}\";").unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<N: Ord + Clone> FromIterator<(N, N)> for Graph<N> {
+    fn from_iter<T: IntoIterator<Item = (N, N)>>(iter: T) -> Self {
+        let mut graph = Graph::new();
+        iter.into_iter().for_each(|(a, b)| graph.add_edge(a, b));
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETWORK: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test_parse() {
+        let graph = Graph::parse(NETWORK).unwrap();
+        assert_eq!(graph.node_count(), 16);
+        assert_eq!(graph.edge_count(), 32);
+        assert!(graph.has_edge(&"tc", &"kh"));
+        assert!(!graph.has_edge(&"tc", &"de"));
+
+        let err = Graph::parse("kh-tc\nqp").unwrap_err();
+        assert_eq!(err.line_number, Some(2));
+    }
+
+    #[test]
+    fn test_triangles() {
+        let graph = Graph::parse(NETWORK).unwrap();
+        let triangles = graph.triangles();
+        assert_eq!(triangles.len(), 12);
+        let with_t = triangles
+            .iter()
+            .filter(|triangle| triangle.iter().any(|node| node.starts_with('t')))
+            .count();
+        assert_eq!(with_t, 7);
+        assert!(triangles.contains(&["co", "de", "ta"]));
+    }
+
+    #[test]
+    fn test_max_clique() {
+        let graph = Graph::parse(NETWORK).unwrap();
+        let clique = graph.max_clique().unwrap();
+        assert_eq!(
+            clique.into_iter().collect::<Vec<_>>().join(","),
+            "co,de,ka,ta"
+        );
+    }
+
+    #[test]
+    fn test_max_clique_ties() {
+        // two triangles of the same size, the one with the smallest nodes wins
+        let graph = [(4, 5), (5, 6), (4, 6), (1, 2), (2, 3), (1, 3)]
+            .into_iter()
+            .collect::<Graph<_>>();
+        assert_eq!(graph.max_clique(), Some(BTreeSet::from([1, 2, 3])));
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let mut graph = [(1, 2), (2, 3), (1, 3)].into_iter().collect::<Graph<_>>();
+        graph.add_edge(2, 2);
+        graph.add_edge(7, 7);
+        assert!(!graph.has_edge(&2, &2));
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.max_clique(), Some(BTreeSet::from([1, 2, 3])));
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        // two triangles sharing an edge, and a separate edge
+        let graph = [(1, 2), (2, 3), (1, 3), (2, 4), (3, 4), (5, 6)]
+            .into_iter()
+            .collect::<Graph<_>>();
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![
+                BTreeSet::from([1, 2, 3]),
+                BTreeSet::from([2, 3, 4]),
+                BTreeSet::from([5, 6])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let mut graph = [(1, 2), (2, 3), (5, 6)].into_iter().collect::<Graph<_>>();
+        graph.add_node(4);
+        assert_eq!(
+            graph.components(),
+            vec![
+                BTreeSet::from([1, 2, 3]),
+                BTreeSet::from([4]),
+                BTreeSet::from([5, 6])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = [(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (6, 7)]
+            .into_iter()
+            .collect::<Graph<_>>();
+        assert_eq!(graph.shortest_path(&1, &4), Some(vec![1, 5, 4]));
+        assert_eq!(graph.shortest_path(&1, &1), Some(vec![1]));
+        assert_eq!(graph.shortest_path(&1, &6), None);
+        assert_eq!(graph.shortest_path(&8, &8), None);
+        assert_eq!(graph.shortest_path(&1, &8), None);
+
+        let distances = graph.distances(&1);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(distances[&4], 2);
+        assert!(!distances.contains_key(&7));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::parse("a-b\nb-c").unwrap();
+        graph.add_node("d");
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    \"d\";\n    \"a\" -- \"b\";\n    \"b\" -- \"c\";\n}\n"
+        );
+    }
+}
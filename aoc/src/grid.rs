@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::coord::Coordinate;
 use crate::direction::Direction;
+use crate::dsu::DisjointSet;
 
 #[derive(Debug)]
 pub struct Grid<T> {
@@ -150,15 +151,43 @@ where
             north, north_east, east, south_east, south, south_west, west, north_west,
         ]
     }
+
+    /// Group all cells into connected regions in a single pass, where neighbouring cells belong
+    /// to the same region when `same_region` holds for their values.
+    pub fn regions(&self, same_region: impl Fn(&T, &T) -> bool) -> Vec<HashSet<Coordinate<usize>>> {
+        let mut sets = DisjointSet::new();
+        for (&coord, value) in &self.map {
+            sets.insert(coord);
+            // only look right and down, the other neighbours look back at this cell
+            for neighbour in [coord + Coordinate::new(1, 0), coord + Coordinate::new(0, 1)] {
+                if let Some(other) = self.map.get(&neighbour) {
+                    if same_region(value, other) {
+                        sets.union(coord, neighbour);
+                    }
+                }
+            }
+        }
+        sets.components()
+            .into_iter()
+            .map(|region| region.into_iter().collect())
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::construct("AAAA\nBBCD\nBBCC\nEEEC", &|c| c);
+        let mut regions = grid.regions(|a, b| a == b);
+        regions.sort_by_key(|region| *region.iter().min().unwrap());
 
-    // #[test]
-    // fn it_works() {
-    //     let result = add(2, 2);
-    //     assert_eq!(result, 4);
-    // }
+        let sizes = regions.iter().map(|r| r.len()).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![4, 4, 3, 4, 1]);
+        // the C region is ordered after E, since coordinates compare by x first
+        assert!(regions[3].contains(&Coordinate::new(2, 1)));
+        assert!(regions[3].contains(&Coordinate::new(3, 3)));
+    }
 }
@@ -0,0 +1,191 @@
+//! Loading puzzle inputs at runtime.
+//!
+//! Inputs are personal and not part of the repository. By default the input of day `N` is read
+//! from `inputs/day-N.txt`, or `inputs/YYYY/day-N.txt` for other years than the default one,
+//! where the `inputs` directory can be moved with the `AOC_INPUT_DIR` environment variable. A
+//! single input can also be given as a path, or `-` for stdin.
+//!
+//! Inputs that are still at their old place, `day-N/src/input.txt` next to the solution, are read
+//! from there with a hint to move them.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::solution::DEFAULT_YEAR;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read an input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `day-N.txt` in the given inputs directory, see [`path_in`]
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a file
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// see [`default_dir`]
+    pub fn default_dir() -> Source {
+        Source::Dir(default_dir())
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let (path, result) = match self {
+            Source::Dir(dir) => {
+                let path = path_in(dir, year, day);
+                let result = std::fs::read_to_string(&path);
+                match result {
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        match read_legacy(Path::new("."), year, day, &path) {
+                            Some(input) => (Some(path), Ok(input)),
+                            None => (Some(path), Err(e)),
+                        }
+                    }
+                    result => (Some(path), result),
+                }
+            }
+            Source::File(path) => (Some(path.clone()), std::fs::read_to_string(path)),
+            Source::Stdin => {
+                let mut input = String::new();
+                (None, io::stdin().read_to_string(&mut input).map(|_| input))
+            }
+        };
+        result.map_err(|error| InputError { day, path, error })
+    }
+}
+
+/// `AOC_INPUT_DIR`, or `inputs` in the current directory
+pub fn default_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from)
+}
+
+/// location of the input of `day` of `year` in the inputs directory `dir`
+pub fn path_in(dir: &Path, year: u32, day: u32) -> PathBuf {
+    let file = format!("day-{day}.txt");
+    if year == DEFAULT_YEAR {
+        dir.join(file)
+    } else {
+        dir.join(year.to_string()).join(file)
+    }
+}
+
+/// where inputs of the default year used to be kept, `day-N/src/input.txt` under `root`
+pub fn legacy_path(root: &Path, day: u32) -> PathBuf {
+    root.join(format!("day-{day}"))
+        .join("src")
+        .join("input.txt")
+}
+
+/// the input at its old place, telling to move it to `path`
+fn read_legacy(root: &Path, year: u32, day: u32, path: &Path) -> Option<String> {
+    if year != DEFAULT_YEAR {
+        return None;
+    }
+    let legacy = legacy_path(root, day);
+    let input = std::fs::read_to_string(&legacy).ok()?;
+    eprintln!(
+        "hint: reading the input of day {day} from {}, move it to {}",
+        legacy.display(),
+        path.display()
+    );
+    Some(input)
+}
+
+/// Input that could not be read, either a file (`path`) or stdin
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u32,
+    pub path: Option<PathBuf>,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) if self.error.kind() == io::ErrorKind::NotFound => write!(
+                f,
+                "no input for day {} at {}, save it there, set {INPUT_DIR_VAR} or pass the path explicitly",
+                self.day,
+                path.display()
+            ),
+            Some(path) => write!(
+                f,
+                "cannot read input for day {} from {}: {}",
+                self.day,
+                path.display(),
+                self.error
+            ),
+            None => write!(
+                f,
+                "cannot read input for day {} from stdin: {}",
+                self.day, self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("day-7.txt"),
+            Source::File(PathBuf::from("day-7.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(path_in(&dir, DEFAULT_YEAR, 3), "mul(2,4)").unwrap();
+
+        let source = Source::Dir(dir.clone());
+        assert_eq!(source.read(DEFAULT_YEAR, 3).unwrap(), "mul(2,4)");
+        let err = source.read(DEFAULT_YEAR, 4).unwrap_err();
+        assert_eq!(err.path, Some(dir.join("day-4.txt")));
+        assert!(err.to_string().starts_with("no input for day 4 at "));
+        let err = source.read(2023, 3).unwrap_err();
+        assert_eq!(err.path, Some(dir.join("2023").join("day-3.txt")));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_legacy() {
+        let root = std::env::temp_dir().join(format!("aoc-legacy-{}", std::process::id()));
+        let legacy = legacy_path(&root, 5);
+        std::fs::create_dir_all(legacy.parent().unwrap()).unwrap();
+        std::fs::write(&legacy, "47|53").unwrap();
+
+        let path = path_in(&root.join("inputs"), DEFAULT_YEAR, 5);
+        assert_eq!(
+            read_legacy(&root, DEFAULT_YEAR, 5, &path).as_deref(),
+            Some("47|53")
+        );
+        assert_eq!(read_legacy(&root, DEFAULT_YEAR, 6, &path), None);
+        assert_eq!(read_legacy(&root, 2023, 5, &path), None);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
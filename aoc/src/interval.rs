@@ -0,0 +1,554 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::ops::Sub;
+
+/// Half-open interval `[start, end)`
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        debug_assert!(start <= end, "interval must not end before it starts");
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = max(self.start, other.start);
+        let end = min(self.end, other.end);
+        (start < end).then_some(Self { start, end })
+    }
+
+    /// union of two intervals that overlap or touch
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        (self.start <= other.end && other.start <= self.end).then(|| Self {
+            start: min(self.start, other.start),
+            end: max(self.end, other.end),
+        })
+    }
+
+    /// split into the parts before and from `at`, either of which may be empty
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end);
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Interval<T> {
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+}
+
+type Tree<T> = Option<Box<Node<T>>>;
+
+/// treap node, ordered by interval start and heap-ordered by priority
+#[derive(Clone)]
+struct Node<T> {
+    interval: Interval<T>,
+    priority: u64,
+    // augmented data of the subtree rooted at this node
+    size: usize,
+    max_len: T,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Node<T> {
+    fn new(interval: Interval<T>, priority: u64) -> Box<Self> {
+        Box::new(Self {
+            interval,
+            priority,
+            size: 1,
+            max_len: interval.len(),
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update(&mut self) {
+        self.size = 1;
+        self.max_len = self.interval.len();
+        for child in [&self.left, &self.right].into_iter().flatten() {
+            self.size += child.size;
+            self.max_len = max(self.max_len, child.max_len);
+        }
+    }
+}
+
+/// join two trees where every interval in `a` comes before every interval in `b`
+fn merge<T: Copy + Ord + Sub<Output = T>>(a: Tree<T>, b: Tree<T>) -> Tree<T> {
+    match (a, b) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                a.update();
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                b.update();
+                Some(b)
+            }
+        }
+    }
+}
+
+/// split into intervals starting before `key` and the rest, with `inclusive` also moving
+/// intervals that start exactly at `key` to the left
+fn split<T: Copy + Ord + Sub<Output = T>>(
+    tree: Tree<T>,
+    key: T,
+    inclusive: bool,
+) -> (Tree<T>, Tree<T>) {
+    match tree {
+        None => (None, None),
+        Some(mut node) => {
+            let start = node.interval.start;
+            if start < key || (inclusive && start == key) {
+                let (left, right) = split(node.right.take(), key, inclusive);
+                node.right = left;
+                node.update();
+                (Some(node), right)
+            } else {
+                let (left, right) = split(node.left.take(), key, inclusive);
+                node.left = right;
+                node.update();
+                (left, Some(node))
+            }
+        }
+    }
+}
+
+/// remove the last interval from the tree
+fn pop_last<T: Copy + Ord + Sub<Output = T>>(tree: Tree<T>) -> (Tree<T>, Option<Interval<T>>) {
+    match tree {
+        None => (None, None),
+        Some(mut node) => match node.right.take() {
+            None => (node.left.take(), Some(node.interval)),
+            Some(right) => {
+                let (rest, last) = pop_last(Some(right));
+                node.right = rest;
+                node.update();
+                (Some(node), last)
+            }
+        },
+    }
+}
+
+/// Set of values stored as sorted, disjoint and non-adjacent intervals.
+///
+/// Inserting, removing and looking up intervals take logarithmic time (expected), as does
+/// finding the first interval that can hold a given number of values.
+#[derive(Clone)]
+pub struct RangeSet<T> {
+    root: Tree<T>,
+    seed: u64,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            root: None,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of disjoint intervals in the set
+    pub fn len(&self) -> usize {
+        self.root.as_ref().map_or(0, |node| node.size)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn node(&mut self, interval: Interval<T>) -> Tree<T> {
+        // xorshift64, the priorities only need to look random
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        Some(Node::new(interval, self.seed))
+    }
+
+    /// add all values in `interval`, merging it with any interval it overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        let (left, right) = split(self.root.take(), interval.start, false);
+        let (mut left, last) = pop_last(left);
+        if let Some(last) = last {
+            match merged.merge(&last) {
+                Some(union) => merged = union,
+                None => left = merge(left, self.node(last)),
+            }
+        }
+        // everything starting within (or right at the end of) the new interval is absorbed
+        let (middle, right) = split(right, merged.end, true);
+        if let (_, Some(last)) = pop_last(middle) {
+            merged.end = max(merged.end, last.end);
+        }
+        let node = self.node(merged);
+        self.root = merge(merge(left, node), right);
+    }
+
+    /// remove all values in `interval`, splitting any interval that only partially overlaps it
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (left, right) = split(self.root.take(), interval.start, false);
+        let (mut left, last) = pop_last(left);
+        let mut tail = None;
+        if let Some(last) = last {
+            let (before, after) = last.split_at(interval.start);
+            left = merge(left, self.node(before));
+            tail = after.split_at(interval.end).1.into_non_empty();
+        }
+        let (middle, right) = split(right, interval.end, false);
+        if let (_, Some(last)) = pop_last(middle) {
+            tail = last.split_at(interval.end).1.into_non_empty();
+        }
+        let tail = tail.and_then(|tail| self.node(tail));
+        self.root = merge(merge(left, tail), right);
+    }
+
+    /// the interval containing `value`, if any
+    pub fn get(&self, value: T) -> Option<Interval<T>> {
+        let mut node = self.root.as_deref();
+        while let Some(current) = node {
+            if value < current.interval.start {
+                node = current.left.as_deref();
+            } else if current.interval.contains(value) {
+                return Some(current.interval);
+            } else {
+                node = current.right.as_deref();
+            }
+        }
+        None
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// The first (lowest) interval holding at least `size` values.
+    ///
+    /// When the set tracks free space, this is the first gap a block of `size` fits in.
+    pub fn first_gap(&self, size: T) -> Option<Interval<T>> {
+        let mut node = self.root.as_deref().filter(|root| root.max_len >= size);
+        while let Some(current) = node {
+            match current.left.as_deref() {
+                Some(left) if left.max_len >= size => node = Some(left),
+                _ if current.interval.len() >= size => return Some(current.interval),
+                _ => node = current.right.as_deref(),
+            }
+        }
+        None
+    }
+
+    /// Split the set at `at`, keeping the values before it and returning the rest.
+    pub fn split_off(&mut self, at: T) -> RangeSet<T> {
+        let (left, right) = split(self.root.take(), at, false);
+        let (mut left, last) = pop_last(left);
+        let mut other = RangeSet {
+            root: right,
+            seed: self.seed.rotate_left(32),
+        };
+        if let Some(last) = last {
+            let (before, after) = last.split_at(at);
+            left = merge(left, self.node(before));
+            if !after.is_empty() {
+                let node = other.node(after);
+                other.root = merge(node, other.root.take());
+            }
+        }
+        self.root = left;
+        other
+    }
+
+    /// all values that are in both sets
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = RangeSet::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if let Some(overlap) = x.intersection(y) {
+                result.insert(overlap);
+            }
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        result
+    }
+
+    /// all values that are in either set
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    /// intervals in ascending order
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    fn into_non_empty(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<T: Copy> Iterator for Iter<'_, T> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(node.interval)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Extend<Interval<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|interval| self.insert(interval));
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> PartialEq for RangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Eq for RangeSet<T> {}
+
+impl<T: Copy + Ord + Sub<Output = T> + fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|i| i.start..i.end))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(u32, u32)]) -> RangeSet<u32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &RangeSet<u32>) -> Vec<(u32, u32)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 6);
+        let b = Interval::new(4, 9);
+        assert_eq!(a.len(), 4);
+        assert!(a.contains(2) && !a.contains(6));
+        assert!(a.overlaps(&b));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.merge(&b), Some(Interval::new(2, 9)));
+        assert_eq!(a.merge(&Interval::new(6, 7)), Some(Interval::new(2, 7)));
+        assert_eq!(a.merge(&Interval::new(7, 8)), None);
+        assert_eq!(a.split_at(3), (Interval::new(2, 3), Interval::new(3, 6)));
+        assert_eq!(a.split_at(10), (a, Interval::new(6, 6)));
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = set(&[(0, 2), (5, 7), (10, 12)]);
+        assert_eq!(set.len(), 3);
+
+        // touching intervals are merged
+        set.insert(Interval::new(2, 3));
+        assert_eq!(intervals(&set), vec![(0, 3), (5, 7), (10, 12)]);
+
+        // an interval spanning several others absorbs them
+        set.insert(Interval::new(4, 11));
+        assert_eq!(intervals(&set), vec![(0, 3), (4, 12)]);
+
+        set.insert(Interval::new(5, 6));
+        assert_eq!(intervals(&set), vec![(0, 3), (4, 12)]);
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set = set(&[(0, 10), (20, 30)]);
+        set.remove(Interval::new(3, 5));
+        assert_eq!(intervals(&set), vec![(0, 3), (5, 10), (20, 30)]);
+
+        set.remove(Interval::new(8, 25));
+        assert_eq!(intervals(&set), vec![(0, 3), (5, 8), (25, 30)]);
+
+        set.remove(Interval::new(0, 100));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_get() {
+        let set = set(&[(0, 2), (5, 7)]);
+        assert_eq!(set.get(6), Some(Interval::new(5, 7)));
+        assert!(set.contains(0));
+        assert!(!set.contains(2));
+        assert!(!set.contains(9));
+    }
+
+    #[test]
+    fn test_first_gap() {
+        let set = set(&[(2, 3), (5, 8), (10, 11), (12, 16)]);
+        assert_eq!(set.first_gap(1), Some(Interval::new(2, 3)));
+        assert_eq!(set.first_gap(2), Some(Interval::new(5, 8)));
+        assert_eq!(set.first_gap(4), Some(Interval::new(12, 16)));
+        assert_eq!(set.first_gap(5), None);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut set = set(&[(0, 4), (6, 10)]);
+        let rest = set.split_off(8);
+        assert_eq!(intervals(&set), vec![(0, 4), (6, 8)]);
+        assert_eq!(intervals(&rest), vec![(8, 10)]);
+    }
+
+    #[test]
+    fn test_intersection_union() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(
+            intervals(&a.intersection(&b)),
+            vec![(3, 5), (10, 12), (14, 15)]
+        );
+        assert_eq!(intervals(&a.union(&b)), vec![(0, 20)]);
+    }
+
+    #[test]
+    fn test_disk_compaction() {
+        // 2024 day 9 part two on the example disk map, using the set as free space
+        let disk_map = "2333133121414131402";
+        let mut free = RangeSet::new();
+        let mut files = Vec::new();
+        let mut position = 0;
+        for (i, size) in disk_map
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .enumerate()
+        {
+            let span = Interval::new(position, position + size);
+            if i % 2 == 0 {
+                files.push(span);
+            } else {
+                free.insert(span);
+            }
+            position += size;
+        }
+        for file in files.iter_mut().rev() {
+            if let Some(gap) = free
+                .first_gap(file.len())
+                .filter(|gap| gap.start < file.start)
+            {
+                free.insert(*file);
+                *file = Interval::new(gap.start, gap.start + file.len());
+                free.remove(*file);
+            }
+        }
+        let checksum: u32 = files
+            .iter()
+            .enumerate()
+            .map(|(id, file)| {
+                (file.start..file.end)
+                    .map(|pos| pos * id as u32)
+                    .sum::<u32>()
+            })
+            .sum();
+        assert_eq!(checksum, 2858);
+    }
+
+    #[test]
+    fn test_against_bitmap() {
+        // compare random inserts and removes with a plain bitmap
+        let mut bitmap = [false; 64];
+        let mut set = RangeSet::new();
+        let mut rng = 12345_u64;
+        for _ in 0..2000 {
+            rng = rng
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let a = (rng >> 33) as u32 % 64;
+            let b = (rng >> 45) as u32 % 64;
+            let interval = Interval::new(a.min(b), a.max(b));
+            let add = !(rng >> 20).is_multiple_of(3);
+            for value in interval.start..interval.end {
+                bitmap[value as usize] = add;
+            }
+            if add {
+                set.insert(interval);
+            } else {
+                set.remove(interval);
+            }
+            for (value, &bit) in bitmap.iter().enumerate() {
+                assert_eq!(set.contains(value as u32), bit);
+            }
+            // intervals stay disjoint and never touch
+            let all = intervals(&set);
+            assert!(all.windows(2).all(|w| w[0].1 < w[1].0));
+            assert_eq!(set.len(), all.len());
+        }
+    }
+}
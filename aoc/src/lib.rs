@@ -1,3 +1,15 @@
+pub mod alloc;
+pub mod checked;
+pub mod combinator;
 pub mod coord;
+pub mod digits;
 pub mod direction;
+pub mod dsu;
+pub mod graph;
 pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod memo;
+pub mod parse;
+pub mod search;
+pub mod solution;
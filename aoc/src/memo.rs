@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Number of cache hits and misses recorded by a `Memo`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Stats {
+    pub fn lookups(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    /// fraction of lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            n => self.hits as f64 / n as f64,
+        }
+    }
+}
+
+/// Cache for recursive functions, keyed by the argument (tuple)
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the cached value for `key`, or compute and cache it.
+    ///
+    /// `compute` receives the memo itself, so it can recurse through `get_or_compute`:
+    ///
+    /// ```
+    /// use aoc::memo::Memo;
+    ///
+    /// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+    ///     memo.get_or_compute(n, |memo, &n| match n {
+    ///         0 | 1 => n,
+    ///         n => fib(memo, n - 1) + fib(memo, n - 2),
+    ///     })
+    /// }
+    ///
+    /// let mut memo = Memo::new();
+    /// assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    /// assert_eq!(memo.stats().misses, 91);
+    /// ```
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// drop all cached values and reset the statistics
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = Stats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_compute(n, |memo, &n| match n {
+            0 | 1 => n,
+            n => fib(memo, n - 1) + fib(memo, n - 2),
+        })
+    }
+
+    // number of stones after blinking `blinks` times at `stone` (2024 day 11)
+    fn stone_count(memo: &mut Memo<(u64, usize), u64>, stone: u64, blinks: usize) -> u64 {
+        memo.get_or_compute((stone, blinks), |memo, &(stone, blinks)| {
+            if blinks == 0 {
+                return 1;
+            }
+            if stone == 0 {
+                return stone_count(memo, 1, blinks - 1);
+            }
+            let digits = stone.ilog10() + 1;
+            if digits % 2 == 0 {
+                let half = 10_u64.pow(digits / 2);
+                stone_count(memo, stone / half, blinks - 1)
+                    + stone_count(memo, stone % half, blinks - 1)
+            } else {
+                stone_count(memo, stone * 2024, blinks - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn test_fib() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(&mut memo, 50), 12586269025);
+        assert_eq!(memo.len(), 51);
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut memo = Memo::new();
+        fib(&mut memo, 10);
+        // every n in 0..=10 is computed once, and fib(n - 2) is a hit for n in 3..=10
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 8,
+                misses: 11
+            }
+        );
+
+        fib(&mut memo, 10);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 9,
+                misses: 11
+            }
+        );
+        assert_eq!(memo.stats().lookups(), 20);
+    }
+
+    #[test]
+    fn test_stone_count() {
+        let mut memo = Memo::new();
+        let count: u64 = [125, 17]
+            .iter()
+            .map(|&s| stone_count(&mut memo, s, 75))
+            .sum();
+        assert_eq!(count, 65601038650482);
+        // only a few thousand distinct (stone, blinks) pairs are ever computed
+        assert!(memo.stats().misses < 4000);
+        assert!(memo.stats().hits > 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut memo = Memo::new();
+        fib(&mut memo, 5);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+        assert_eq!(memo.get(&5), None);
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned by the parse helpers, holding the offending line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, when the helper was given more than a single line
+    pub line_number: Option<usize>,
+    pub line: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line_number: None,
+            line: line.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// attach the (1-based) line number the error occurred on
+    pub fn at_line(mut self, line_number: usize) -> Self {
+        self.line_number = Some(line_number);
+        self
+    }
+
+    /// move the line number down by `lines`, for an error in a block that follows them
+    pub fn after_lines(mut self, lines: usize) -> Self {
+        self.line_number = self.line_number.map(|n| n + lines);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line_number) = self.line_number {
+            write!(f, "line {line_number}: ")?;
+        }
+        write!(f, "expected {} in {:?}", self.expected, self.line)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// byte ranges of all (optionally negative) numbers in `line`
+///
+/// A `-` is only treated as a sign when it is not preceded by a letter or digit, so ranges like
+/// `3-5` and labels like `a-b` are not read as negative numbers.
+fn number_spans(line: &str) -> Vec<(usize, usize)> {
+    let bytes = line.as_bytes();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if signed || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            spans.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+    spans
+}
+
+/// Parse `number`, a part of `line`, reporting the whole line when it is not a `T`
+pub fn number<T: FromStr>(line: &str, number: &str) -> Result<T, ParseError> {
+    number.parse().map_err(|_| {
+        ParseError::new(
+            line,
+            format!("{number:?} to fit in {}", std::any::type_name::<T>()),
+        )
+    })
+}
+
+/// Extract all integers from `line`, ignoring everything in between.
+///
+/// ```
+/// let numbers = aoc::parse::ints::<i32>("p=0,4 v=3,-3").unwrap();
+/// assert_eq!(numbers, vec![0, 4, 3, -3]);
+/// ```
+pub fn ints<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    number_spans(line)
+        .into_iter()
+        .map(|(start, end)| number(line, &line[start..end]))
+        .collect()
+}
+
+/// Split `input` into blocks separated by one or more blank lines
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .flat_map(|block| block.split("\r\n\r\n"))
+        .map(|block| block.trim_matches(['\r', '\n']))
+        .filter(|block| !block.is_empty())
+}
+
+/// Number of lines of `input` before `part`, a slice of it such as one of its `blocks`, or 0 when
+/// `part` is not a slice of `input`
+pub fn lines_before(input: &str, part: &str) -> usize {
+    let input_range = input.as_bytes().as_ptr_range();
+    let part_range = part.as_bytes().as_ptr_range();
+    if part_range.start < input_range.start || part_range.end > input_range.end {
+        return 0;
+    }
+    let start = part_range.start as usize - input_range.start as usize;
+    input[..start].matches('\n').count()
+}
+
+/// Lines of a rectangular grid, failing on the first line with a different width
+pub fn grid_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+    let lines = input.trim_end().lines().collect::<Vec<_>>();
+    let width = lines.first().map_or(0, |line| line.len());
+    for (i, line) in lines.iter().enumerate() {
+        if line.len() != width {
+            return Err(
+                ParseError::new(line, format!("a grid row of width {width}")).at_line(i + 1),
+            );
+        }
+    }
+    Ok(lines)
+}
+
+/// Parse a line of the form `<left><sep><right>`, such as `47|53` or `3   4`.
+pub fn pair<T: FromStr>(line: &str, sep: &str) -> Result<(T, T), ParseError> {
+    let (left, right) = line
+        .split_once(sep)
+        .ok_or_else(|| ParseError::new(line, format!("separator {sep:?}")))?;
+    Ok((number(line, left.trim())?, number(line, right.trim())?))
+}
+
+/// Parse every non-empty line of `input` with `f`, adding the line number to its errors
+pub fn lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Parse every non-empty line of `input` with `pair`
+pub fn pairs<T: FromStr>(input: &str, sep: &str) -> Result<Vec<(T, T)>, ParseError> {
+    lines(input, |line| pair(line, sep))
+}
+
+/// Parse the number that directly follows `label` in `line`.
+///
+/// ```
+/// let x = aoc::parse::field::<i64>("Button A: X+94, Y+34", "X+").unwrap();
+/// assert_eq!(x, 94);
+/// ```
+pub fn field<T: FromStr>(line: &str, label: &str) -> Result<T, ParseError> {
+    let start = line
+        .find(label)
+        .map(|idx| idx + label.len())
+        .ok_or_else(|| ParseError::new(line, format!("field {label:?}")))?;
+    let rest = &line[start..];
+    let sign = usize::from(rest.starts_with('-'));
+    let end = rest[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(rest.len(), |idx| idx + sign);
+    if end == sign {
+        return Err(ParseError::new(line, format!("a number after {label:?}")));
+    }
+    number(line, &rest[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<isize>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints::<u64>("190: 10 19"), Ok(vec![190, 10, 19]));
+        assert_eq!(ints::<u32>("Button A: X+94, Y+34"), Ok(vec![94, 34]));
+        assert_eq!(ints::<u32>("no numbers"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_ints_hyphen() {
+        // a hyphen between two numbers or after a label is not a sign
+        assert_eq!(ints::<i32>("3-5"), Ok(vec![3, 5]));
+        assert_eq!(ints::<i32>("x1-2"), Ok(vec![1, 2]));
+        assert_eq!(ints::<i32>("a=-7"), Ok(vec![-7]));
+    }
+
+    #[test]
+    fn test_ints_error() {
+        let err = ints::<u8>("1 2 300").unwrap_err();
+        assert_eq!(err.line, "1 2 300");
+        assert!(err.expected.contains("300"));
+        assert!(ints::<u32>("p=-1,2").is_err());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "47|53\n97|13\n\n75,47,61\n\n\n97,61\n";
+        let blocks = blocks(input).collect::<Vec<_>>();
+        assert_eq!(blocks, vec!["47|53\n97|13", "75,47,61", "97,61"]);
+
+        let lines = blocks
+            .iter()
+            .map(|b| lines_before(input, b))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [0, 3, 6]);
+        assert_eq!(lines_before(input, "elsewhere"), 0);
+        assert_eq!(lines_before(input, ""), 0);
+        assert_eq!(lines_before(&input[..10], blocks[2]), 0);
+        let err = pairs::<u32>(blocks[1], ",").unwrap_err();
+        assert_eq!(err.after_lines(lines[1]).line_number, Some(4));
+    }
+
+    #[test]
+    fn test_grid_lines() {
+        assert_eq!(grid_lines("abc\ndef\n"), Ok(vec!["abc", "def"]));
+
+        let err = grid_lines("abc\nde\nfgh").unwrap_err();
+        assert_eq!(err.line_number, Some(2));
+        assert_eq!(err.line, "de");
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected a grid row of width 3 in \"de\""
+        );
+    }
+
+    #[test]
+    fn test_pairs() {
+        assert_eq!(pair::<u32>("47|53", "|"), Ok((47, 53)));
+        assert_eq!(pair::<u32>("3   4", " "), Ok((3, 4)));
+        assert_eq!(pairs::<u32>("1|2\n3|4\n", "|"), Ok(vec![(1, 2), (3, 4)]));
+
+        let err = pairs::<u32>("1|2\n3,4", "|").unwrap_err();
+        assert_eq!(err.line_number, Some(2));
+        assert_eq!(err.expected, "separator \"|\"");
+    }
+
+    #[test]
+    fn test_lines() {
+        let parsed = lines("1 2\n\n3\n", ints::<u32>);
+        assert_eq!(parsed, Ok(vec![vec![1, 2], vec![3]]));
+
+        let err = lines("1\n2\nx", |line| number::<u32>(line, line)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3: expected \"x\" to fit in u32 in \"x\""
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        let line = "Button A: X+94, Y+34";
+        assert_eq!(field::<i64>(line, "X+"), Ok(94));
+        assert_eq!(field::<i64>(line, "Y+"), Ok(34));
+        assert_eq!(field::<i64>("p=3,-3 v=-1,2", "v="), Ok(-1));
+        assert_eq!(field::<i64>("Prize: X=8400", "X="), Ok(8400));
+        assert!(field::<i64>(line, "Z+").is_err());
+        assert!(field::<i64>("X=abc", "X=").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+/// Binary operator for `Search`, returning `None` when the result is not valid (e.g. overflow)
+#[derive(Clone, Copy)]
+pub struct Op<T> {
+    pub symbol: &'static str,
+    pub apply: fn(T, T) -> Option<T>,
+}
+
+impl<T> fmt::Debug for Op<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Op({})", self.symbol)
+    }
+}
+
+/// common operators on `u64`, all failing on overflow
+pub mod ops {
+    use super::Op;
+    use crate::{checked, digits};
+
+    pub const ADD: Op<u64> = Op {
+        symbol: "+",
+        apply: u64::checked_add,
+    };
+
+    pub const MUL: Op<u64> = Op {
+        symbol: "*",
+        apply: u64::checked_mul,
+    };
+
+    /// Concatenation of the decimal digits, `12 || 345 = 12345`. Unlike the other operators it
+    /// panics on overflow when checking is enabled, see [`crate::checked`].
+    pub const CONCAT: Op<u64> = Op {
+        symbol: "||",
+        apply: concat,
+    };
+
+    fn concat(a: u64, b: u64) -> Option<u64> {
+        let result = digits::concat(a, b);
+        if result.is_none() && checked::is_enabled() {
+            let overflow = checked::Overflow {
+                lhs: a.to_string(),
+                op: "||",
+                rhs: b.to_string(),
+            };
+            panic!("{overflow}");
+        }
+        result
+    }
+}
+
+/// Numbers combined by operators, evaluated strictly left to right
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression<T> {
+    pub numbers: Vec<T>,
+    pub operators: Vec<&'static str>,
+}
+
+impl<T: fmt::Display> fmt::Display for Expression<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, number) in self.numbers.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", self.operators[i - 1])?;
+            }
+            write!(f, "{number}")?;
+        }
+        Ok(())
+    }
+}
+
+type Prune<'a, T> = Box<dyn Fn(&T, &T) -> bool + 'a>;
+
+/// Depth-first search for operators that combine a list of numbers into a target value.
+///
+/// ```
+/// use aoc::search::{ops, Search};
+///
+/// let search = Search::new(&[ops::ADD, ops::MUL]).prune(|value, target| value > target);
+/// let expression = search.find(&[81, 40, 27], 3267).unwrap();
+/// assert_eq!(expression.to_string(), "81 + 40 * 27");
+/// ```
+pub struct Search<'a, T> {
+    operators: &'a [Op<T>],
+    prune: Option<Prune<'a, T>>,
+}
+
+impl<'a, T: Copy + PartialEq> Search<'a, T> {
+    pub fn new(operators: &'a [Op<T>]) -> Self {
+        Self {
+            operators,
+            prune: None,
+        }
+    }
+
+    /// Skip every branch where `prune(partial value, target)` holds, such as "the value already
+    /// exceeds the target" when no operator can make a value smaller.
+    pub fn prune(mut self, prune: impl Fn(&T, &T) -> bool + 'a) -> Self {
+        self.prune = Some(Box::new(prune));
+        self
+    }
+
+    /// the first expression over `numbers` (in operator order) that evaluates to `target`
+    pub fn find(&self, numbers: &[T], target: T) -> Option<Expression<T>> {
+        let (&first, rest) = numbers.split_first()?;
+        let mut path = Vec::with_capacity(rest.len());
+        self.dfs(rest, first, &target, &mut path)
+            .then(|| Expression {
+                numbers: numbers.to_vec(),
+                operators: path.iter().map(|&i| self.operators[i].symbol).collect(),
+            })
+    }
+
+    pub fn solvable(&self, numbers: &[T], target: T) -> bool {
+        let Some((&first, rest)) = numbers.split_first() else {
+            return false;
+        };
+        self.dfs(rest, first, &target, &mut Vec::with_capacity(rest.len()))
+    }
+
+    fn dfs(&self, numbers: &[T], value: T, target: &T, path: &mut Vec<usize>) -> bool {
+        let Some((&next, rest)) = numbers.split_first() else {
+            return value == *target;
+        };
+        if self
+            .prune
+            .as_ref()
+            .is_some_and(|prune| prune(&value, target))
+        {
+            return false;
+        }
+        for (i, op) in self.operators.iter().enumerate() {
+            if let Some(value) = (op.apply)(value, next) {
+                path.push(i);
+                if self.dfs(rest, value, target, path) {
+                    return true;
+                }
+                path.pop();
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        assert_eq!((ops::CONCAT.apply)(12, 345), Some(12345));
+        assert_eq!((ops::CONCAT.apply)(15, 6), Some(156));
+        assert_eq!((ops::CONCAT.apply)(7, 0), Some(70));
+        assert_eq!((ops::CONCAT.apply)(7, 10), Some(710));
+        assert_eq!((ops::MUL.apply)(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let search = Search::new(&[ops::ADD, ops::MUL]);
+        let expression = search.find(&[10, 19], 190).unwrap();
+        assert_eq!(expression.operators, vec!["*"]);
+        assert_eq!(expression.to_string(), "10 * 19");
+
+        assert_eq!(search.find(&[17, 5], 83), None);
+        assert_eq!(search.find(&[], 0), None);
+        assert_eq!(search.find(&[5], 5).unwrap().to_string(), "5");
+    }
+
+    #[test]
+    fn test_concat() {
+        let search = Search::new(&[ops::ADD, ops::MUL, ops::CONCAT]);
+        let expression = search.find(&[6, 8, 6, 15], 7290).unwrap();
+        assert_eq!(expression.to_string(), "6 * 8 || 6 * 15");
+        assert!(search.solvable(&[15, 6], 156));
+        assert!(!search.solvable(&[9, 7, 18, 13], 21037));
+    }
+
+    #[test]
+    #[should_panic(expected = "arithmetic overflow in `18446744073709551615 || 1`")]
+    fn test_concat_overflow_when_checked() {
+        let _enabled = crate::checked::tests::EnabledGuard::new();
+        let _ = (ops::CONCAT.apply)(u64::MAX, 1);
+    }
+
+    #[test]
+    fn test_prune() {
+        // without pruning this would try 2^39 operator sequences
+        let search = Search::new(&[ops::ADD, ops::MUL]).prune(|value, target| value > target);
+        assert!(!search.solvable(&[2; 40], 1));
+        assert!(search.solvable(&[2; 40], 80));
+    }
+}
@@ -0,0 +1,527 @@
+//! Common interface of the daily puzzles, used by the `aoc` runner.
+
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, Usage};
+use crate::input::Source;
+use crate::parse::ParseError;
+
+/// Puzzle answer as submitted, either a number or some text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        }
+    )*};
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// `Part::One` for 1 and `Part::Two` for 2
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+/// Why a part gave no answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// the input is not what the day expects
+    Parse(ParseError),
+    /// The part is not implemented yet, such as the stubs of `aoc new`. Its answer is never
+    /// submitted or recorded.
+    Unsolved,
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => error.fmt(f),
+            SolveError::Unsolved => write!(f, "not implemented yet"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Parse(error) => Some(error),
+            SolveError::Unsolved => None,
+        }
+    }
+}
+
+/// Error of a day on an input it cannot solve: which day, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub year: u32,
+    pub day: u32,
+    pub error: SolveError,
+}
+
+impl AocError {
+    pub fn of<S: Solution + ?Sized>(error: impl Into<SolveError>) -> AocError {
+        AocError {
+            year: S::YEAR,
+            day: S::DAY,
+            error: error.into(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.year {
+            DEFAULT_YEAR => write!(f, "day {}: {}", self.day, self.error),
+            year => write!(f, "day {} of {year}: {}", self.day, self.error),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// name of the only implementation of a part, for days that have no alternatives
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+/// a part together with the name of the implementation that solves it
+pub type Implementation<'a> = (Part, &'a str);
+
+/// implementation of a part of `S`, see [`Solution::IMPLEMENTATIONS`]
+pub type Solver<S> = for<'a> fn(&<S as Solution>::Input<'a>) -> Result<Answer, SolveError>;
+
+/// Year of the top-level `day-N` crates and inputs; other years live in a `YYYY` directory.
+pub const DEFAULT_YEAR: u32 = 2024;
+
+/// One day of the calendar.
+///
+/// The input is parsed once and shared by both parts. Days that have no common parsing step use
+/// the input text itself, and parse it in each part. An input that cannot be parsed is reported
+/// as a `ParseError`, and a part that is not implemented yet as `SolveError::Unsolved`; the
+/// provided methods turn both into an `AocError` naming the day.
+pub trait Solution: 'static {
+    const YEAR: u32 = DEFAULT_YEAR;
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input<'a>;
+
+    /// example inputs from the puzzle, which every implementation of a part must agree on
+    const EXAMPLES: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+
+    /// Every implementation of the parts by name, such as an optimised version next to the
+    /// reference one. The first of each part is the default, `part_one` or `part_two`.
+    const IMPLEMENTATIONS: &'static [(Part, &'static str, Solver<Self>)] = &[
+        (Part::One, DEFAULT_IMPLEMENTATION, Self::part_one),
+        (Part::Two, DEFAULT_IMPLEMENTATION, Self::part_two),
+    ];
+
+    fn solve(input: &str, part: Part) -> Result<Answer, AocError> {
+        let input = Self::parse(input).map_err(AocError::of::<Self>)?;
+        match part {
+            Part::One => Self::part_one(&input),
+            Part::Two => Self::part_two(&input),
+        }
+        .map_err(AocError::of::<Self>)
+    }
+
+    /// parse once and solve `parts`, timing every step and counting its allocations
+    fn run(input: &str, parts: &[Part]) -> Result<Run, AocError> {
+        let (input, parse, parse_memory) = measured(|| Self::parse(input));
+        let input = input.map_err(AocError::of::<Self>)?;
+        let mut run = Run::new(parse, parse_memory);
+        for &part in parts {
+            let (answer, time, memory) = measured(|| match part {
+                Part::One => Self::part_one(&input),
+                Part::Two => Self::part_two(&input),
+            });
+            let answer = answer.map_err(AocError::of::<Self>)?;
+            run.push(part, answer, time, memory);
+        }
+        Ok(run)
+    }
+
+    /// Like [`run`](Solution::run), with the implementation of every part chosen by name. `None`
+    /// when one of the implementations does not exist.
+    fn run_with(input: &str, parts: &[Implementation]) -> Option<Result<Run, AocError>> {
+        let solvers = parts
+            .iter()
+            .map(|&(part, name)| {
+                Self::IMPLEMENTATIONS
+                    .iter()
+                    .find(|&&(p, n, _)| (p, n) == (part, name))
+                    .map(|&(_, _, solve)| (part, solve))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let (input, parse, parse_memory) = measured(|| Self::parse(input));
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => return Some(Err(AocError::of::<Self>(error))),
+        };
+        let mut run = Run::new(parse, parse_memory);
+        for (part, solve) in solvers {
+            let (answer, time, memory) = measured(|| solve(&input));
+            match answer {
+                Ok(answer) => run.push(part, answer, time, memory),
+                Err(error) => return Some(Err(AocError::of::<Self>(error))),
+            }
+        }
+        Some(Ok(run))
+    }
+}
+
+/// time `f` and count its allocations
+fn measured<R>(f: impl FnOnce() -> R) -> (R, Duration, Usage) {
+    let ((result, time), usage) = alloc::measure(|| {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    });
+    (result, time, usage)
+}
+
+/// Answers of a single run, with the time spent on parsing and on each part, and their
+/// allocations when the counting allocator is installed
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Answer, Duration)>,
+    pub parse_memory: Usage,
+    pub memory: Vec<(Part, Usage)>,
+}
+
+impl Run {
+    /// a run that parsed its input and solved nothing yet
+    pub fn new(parse: Duration, parse_memory: Usage) -> Run {
+        Run {
+            parse,
+            parts: Vec::new(),
+            parse_memory,
+            memory: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, part: Part, answer: Answer, time: Duration, memory: Usage) {
+        self.parts.push((part, answer, time));
+        self.memory.push((part, memory));
+    }
+
+    /// allocations of `part`, none if it did not run
+    pub fn memory(&self, part: Part) -> Usage {
+        self.memory
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map_or(Usage::default(), |(_, memory)| *memory)
+    }
+
+    /// time spent on `part`, zero if it did not run
+    pub fn time(&self, part: Part) -> Duration {
+        self.parts
+            .iter()
+            .find(|(p, _, _)| *p == part)
+            .map_or(Duration::ZERO, |(_, _, time)| *time)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|(_, _, time)| *time)
+                .sum::<Duration>()
+    }
+}
+
+/// [`Solution::run_with`] of a type-erased day
+pub type RunWith = fn(&str, &[Implementation]) -> Option<Result<Run, AocError>>;
+
+/// Type-erased `Solution`, so that days can be listed in one table
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub examples: &'static [&'static str],
+    pub solve: fn(&str, Part) -> Result<Answer, AocError>,
+    pub run: fn(&str, &[Part]) -> Result<Run, AocError>,
+    pub run_with: RunWith,
+    implementations: fn(Part) -> Vec<&'static str>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            examples: S::EXAMPLES,
+            solve: S::solve,
+            run: S::run,
+            run_with: S::run_with,
+            implementations: names::<S>,
+        }
+    }
+
+    /// names of the implementations of `part`, the default one first
+    pub fn implementations(&self, part: Part) -> Vec<&'static str> {
+        (self.implementations)(part)
+    }
+
+    /// the implementation called `name` of every part in `parts` that has one, and the default
+    /// implementation of the others
+    pub fn select(&self, parts: &[Part], name: &str) -> Vec<Implementation<'static>> {
+        parts
+            .iter()
+            .map(|&part| {
+                let names = self.implementations(part);
+                let selected = names.iter().find(|&&n| n == name).unwrap_or(&names[0]);
+                (part, *selected)
+            })
+            .collect()
+    }
+}
+
+fn names<S: Solution>(part: Part) -> Vec<&'static str> {
+    S::IMPLEMENTATIONS
+        .iter()
+        .filter(|&&(p, _, _)| p == part)
+        .map(|&(_, name, _)| name)
+        .collect()
+}
+
+/// Entry point of a single day binary: read the input from the path given as first argument
+/// (`-` for stdin) or from the inputs directory, and print both answers.
+pub fn main<S: Solution>() -> ExitCode {
+    let source = std::env::args()
+        .nth(1)
+        .map_or_else(Source::default_dir, |arg| Source::from_arg(&arg));
+    let input = match source.read(S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match S::run(&input, &Part::ALL) {
+        Ok(run) => {
+            for (part, answer, _) in &run.parts {
+                println!("Part {part}:\n{answer}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+        const TITLE: &'static str = "Sum";
+
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            parse::lines(input, |line| parse::number(line, line))
+        }
+
+        fn part_one(input: &Vec<u32>) -> Result<Answer, SolveError> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part_two(input: &Vec<u32>) -> Result<Answer, SolveError> {
+            Ok(input
+                .iter()
+                .max()
+                .map_or(Answer::from("none"), |&n| n.into()))
+        }
+    }
+
+    #[test]
+    fn test_day() {
+        let day = Day::of::<Sum>();
+        assert_eq!((day.year, day.day, day.title), (DEFAULT_YEAR, 1, "Sum"));
+        assert_eq!((day.solve)("1\n2\n3", Part::One), Ok(Answer::Number(6)));
+        assert_eq!((day.solve)("1\n2\n3", Part::Two).unwrap().to_string(), "3");
+        assert_eq!((day.solve)("", Part::Two).unwrap().to_string(), "none");
+    }
+
+    #[test]
+    fn test_error() {
+        let error = (Day::of::<Sum>().solve)("1\n2\nthree", Part::One).unwrap_err();
+        assert!(matches!(
+            error.error,
+            SolveError::Parse(ParseError {
+                line_number: Some(3),
+                ..
+            })
+        ));
+        assert_eq!(
+            error.to_string(),
+            "day 1: line 3: expected \"three\" to fit in u32 in \"three\""
+        );
+        assert!((Day::of::<Sum>().run)("x", &Part::ALL).is_err());
+
+        let error = AocError {
+            year: 2023,
+            ..error
+        };
+        assert!(error.to_string().starts_with("day 1 of 2023: line 3: "));
+
+        let error = AocError::of::<Sum>(SolveError::Unsolved);
+        assert_eq!(error.to_string(), "day 1: not implemented yet");
+    }
+
+    #[test]
+    fn test_run() {
+        let run = (Day::of::<Sum>().run)("1\n2\n3", &[Part::Two]).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].1, Answer::Number(3));
+        assert_eq!(run.time(Part::One), Duration::ZERO);
+        assert_eq!(run.total(), run.parse + run.time(Part::Two));
+        // the tests install the counting allocator, and parsing collects a vector
+        assert_eq!(run.parse_memory.allocations, 1);
+        assert_eq!(run.memory(Part::One), Usage::default());
+    }
+
+    struct Fast;
+
+    impl Solution for Fast {
+        const DAY: u32 = 2;
+        const TITLE: &'static str = "Fast sum";
+        const EXAMPLES: &'static [&'static str] = &["1\n2\n3"];
+
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            Sum::parse(input)
+        }
+
+        fn part_one(input: &Vec<u32>) -> Result<Answer, SolveError> {
+            Sum::part_one(input)
+        }
+
+        fn part_two(input: &Vec<u32>) -> Result<Answer, SolveError> {
+            Sum::part_two(input)
+        }
+
+        const IMPLEMENTATIONS: &'static [(Part, &'static str, Solver<Self>)] = &[
+            (Part::One, "loop", Self::part_one),
+            (Part::One, "formula", |input| {
+                let n = input.len() as u32;
+                Ok((n * (n + 1) / 2).into())
+            }),
+            (Part::Two, "max", Self::part_two),
+        ];
+    }
+
+    #[test]
+    fn test_implementations() {
+        let day = Day::of::<Sum>();
+        assert_eq!(day.implementations(Part::One), [DEFAULT_IMPLEMENTATION]);
+        assert!(day.examples.is_empty());
+
+        let day = Day::of::<Fast>();
+        assert_eq!(day.implementations(Part::One), ["loop", "formula"]);
+        assert_eq!(day.implementations(Part::Two), ["max"]);
+        let run = (day.run_with)("1\n2\n3", &[(Part::One, "formula"), (Part::Two, "max")])
+            .unwrap()
+            .unwrap();
+        assert_eq!(run.parts[0].1, Answer::Number(6));
+        assert_eq!(run.parts[1].1, Answer::Number(3));
+        assert!((day.run_with)("1\n2\n3", &[(Part::Two, "formula")]).is_none());
+        assert_eq!(
+            day.select(&Part::ALL, "formula"),
+            [(Part::One, "formula"), (Part::Two, "max")]
+        );
+        assert_eq!(day.select(&[Part::Two], "max"), [(Part::Two, "max")]);
+    }
+
+    #[test]
+    fn test_part() {
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::One.number(), 1);
+    }
+}
@@ -0,0 +1,80 @@
+// Advent of Code - Day 1: Historian Hysteria
+
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Solution, SolveError};
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    Ok(parse::pairs::<u32>(input, " ")?.into_iter().unzip())
+}
+
+fn solve_part_one((left, right): &(Vec<u32>, Vec<u32>)) -> u32 {
+    let mut left = left.clone();
+    let mut right = right.clone();
+
+    // sort lists
+    left.sort();
+    right.sort();
+
+    // compute sum of distances
+    std::iter::zip(left, right)
+        .map(|(l, r)| l.abs_diff(r))
+        .sum()
+}
+
+fn solve_part_two((left, right): &(Vec<u32>, Vec<u32>)) -> u32 {
+    // compute similarity score by multiplying each element in left list with its count in right list
+    left.iter()
+        .map(|l| {
+            let count = right.iter().filter(|r| *r == l).count() as u32;
+            l * count
+        })
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 11);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 31);
+    }
+}
@@ -0,0 +1,259 @@
+// Advent of Code - Day 10: Hoof It
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc::coord::Coordinate;
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Solution, SolveError};
+
+fn find_coordinates_by_height(
+    map: &HashMap<Coordinate<usize>, u32>,
+    target_height: u32,
+) -> Vec<Coordinate<usize>> {
+    map.iter()
+        .filter(|(_, &height)| height == target_height)
+        .map(|(&coord, _)| coord)
+        .collect()
+}
+
+fn find_trail(
+    map: &HashMap<Coordinate<usize>, u32>,
+    start: Coordinate<usize>,
+    end: Coordinate<usize>,
+) -> Option<Vec<Coordinate<usize>>> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    let mut came_from: HashMap<Coordinate<usize>, Coordinate<usize>> = HashMap::new();
+
+    queue.push_back(start);
+    visited.insert(start);
+
+    while let Some(current_pos) = queue.pop_front() {
+        if current_pos == end {
+            // reconstruct path, the start is the only position that was not reached from another
+            let mut path = std::iter::successors(Some(end), |pos| came_from.get(pos).copied())
+                .collect::<Vec<_>>();
+            path.reverse();
+            return Some(path);
+        }
+
+        let possible_moves = [
+            Coordinate {
+                x: current_pos.x + 1,
+                y: current_pos.y,
+            },
+            Coordinate {
+                x: current_pos.x.wrapping_sub(1),
+                y: current_pos.y,
+            },
+            Coordinate {
+                x: current_pos.x,
+                y: current_pos.y + 1,
+            },
+            Coordinate {
+                x: current_pos.x,
+                y: current_pos.y.wrapping_sub(1),
+            },
+        ];
+
+        let Some(current_height) = map.get(&current_pos) else {
+            continue;
+        };
+        for next_pos in possible_moves {
+            if let Some(next_height) = map.get(&next_pos) {
+                if *next_height == current_height + 1 && !visited.contains(&next_pos) {
+                    visited.insert(next_pos);
+                    came_from.insert(next_pos, current_pos);
+                    queue.push_back(next_pos);
+                }
+            }
+        }
+    }
+    None // No valid trail found!
+}
+
+fn find_all_trails(
+    map: &HashMap<Coordinate<usize>, u32>,
+    start: Coordinate<usize>,
+    end: Coordinate<usize>,
+) -> Vec<Vec<Coordinate<usize>>> {
+    let mut all_paths = Vec::new();
+    let mut current_path = vec![start];
+    let mut visited = HashSet::new();
+    let mut unique_paths = HashSet::new();
+    visited.insert(start);
+
+    find_all_trails_recursive(
+        map,
+        start,
+        end,
+        &mut current_path,
+        &mut visited,
+        &mut all_paths,
+        &mut unique_paths,
+    );
+
+    all_paths
+}
+
+fn find_all_trails_recursive(
+    map: &HashMap<Coordinate<usize>, u32>,
+    current: Coordinate<usize>,
+    end: Coordinate<usize>,
+    current_path: &mut Vec<Coordinate<usize>>,
+    visited: &mut HashSet<Coordinate<usize>>,
+    all_paths: &mut Vec<Vec<Coordinate<usize>>>,
+    unique_paths: &mut HashSet<Vec<Coordinate<usize>>>,
+) {
+    if current == end {
+        // only add the path if we haven't seen this exact sequence before
+        if unique_paths.insert(current_path.clone()) {
+            all_paths.push(current_path.clone());
+        }
+    }
+    let Some(&current_height) = map.get(&current) else {
+        return;
+    };
+
+    let possible_moves = [
+        Coordinate {
+            x: current.x + 1,
+            y: current.y,
+        },
+        Coordinate {
+            x: current.x.wrapping_sub(1),
+            y: current.y,
+        },
+        Coordinate {
+            x: current.x,
+            y: current.y + 1,
+        },
+        Coordinate {
+            x: current.x,
+            y: current.y.wrapping_sub(1),
+        },
+    ];
+
+    for next_pos in possible_moves.iter() {
+        if let Some(&next_height) = map.get(next_pos) {
+            if next_height == current_height + 1 && !visited.contains(next_pos) {
+                // try this path
+                visited.insert(*next_pos);
+                current_path.push(*next_pos);
+
+                find_all_trails_recursive(
+                    map,
+                    *next_pos,
+                    end,
+                    current_path,
+                    visited,
+                    all_paths,
+                    unique_paths,
+                );
+
+                // backtrack
+                visited.remove(next_pos);
+                current_path.pop();
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Coordinate<usize>, u32>, ParseError> {
+    let rows = parse::lines(input, |line| {
+        line.chars()
+            .map(|ch| {
+                ch.to_digit(10)
+                    .ok_or_else(|| ParseError::new(line, "heights from 0 to 9"))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok(rows
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(move |(x, height)| (Coordinate { x, y }, height))
+        })
+        .collect())
+}
+
+fn solve_part_one(topo_map: &HashMap<Coordinate<usize>, u32>) -> usize {
+    let trailheads = find_coordinates_by_height(topo_map, 0);
+    let tops = find_coordinates_by_height(topo_map, 9);
+
+    let trails = trailheads
+        .iter()
+        .flat_map(|trailhead| {
+            tops.iter()
+                .filter_map(|top| find_trail(topo_map, *trailhead, *top))
+        })
+        .collect::<Vec<_>>();
+
+    trails.len()
+}
+
+fn solve_part_two(topo_map: &HashMap<Coordinate<usize>, u32>) -> usize {
+    let trailheads = find_coordinates_by_height(topo_map, 0);
+    let tops = find_coordinates_by_height(topo_map, 9);
+    trailheads
+        .iter()
+        .flat_map(|trailhead| {
+            tops.iter()
+                .map(|top| find_all_trails(topo_map, *trailhead, *top).len())
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input<'a> = HashMap<Coordinate<usize>, u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 36);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 81);
+    }
+}
@@ -0,0 +1,153 @@
+// Advent of Code - Day 11: Plutonian Pebbles
+
+use std::collections::HashMap;
+
+use aoc::checked::Checked;
+use aoc::digits;
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Part, Solution, SolveError, Solver};
+
+#[derive(Debug, Clone)]
+pub struct Stones(Vec<u64>);
+
+impl Stones {
+    fn blink(&self) -> Stones {
+        let mut stones = Stones(vec![]);
+        let _ = self
+            .0
+            .iter()
+            .map(|stone| match stone {
+                0 => stones.0.push(1),
+                n if digits::num_digits(*n).is_multiple_of(2) => {
+                    let (left, right) = digits::split_at_digit(*n, digits::num_digits(*n) / 2);
+                    stones.0.push(left);
+                    stones.0.push(right);
+                }
+                n => stones.0.push((Checked(*n) * 2024).0),
+            })
+            .collect::<Vec<_>>();
+        stones
+    }
+
+    fn cached_blink(&self, cache: &HashMap<u64, u64>) -> HashMap<u64, u64> {
+        let mut local_cache: HashMap<u64, u64> = HashMap::new();
+
+        for (stone, count) in cache.iter() {
+            match stone {
+                0 => {
+                    local_cache
+                        .entry(1)
+                        .and_modify(|v| {
+                            *v = (Checked(*v) + *count).0;
+                        })
+                        .or_insert(*count);
+                }
+                n if digits::num_digits(*n).is_multiple_of(2) => {
+                    let (left, right) = digits::split_at_digit(*n, digits::num_digits(*n) / 2);
+                    local_cache
+                        .entry(left)
+                        .and_modify(|v| {
+                            *v = (Checked(*v) + *count).0;
+                        })
+                        .or_insert(*count);
+                    local_cache
+                        .entry(right)
+                        .and_modify(|v| {
+                            *v = (Checked(*v) + *count).0;
+                        })
+                        .or_insert(*count);
+                }
+                n => {
+                    local_cache
+                        .entry((Checked(*n) * 2024).0)
+                        .and_modify(|v| {
+                            *v = (Checked(*v) + *count).0;
+                        })
+                        .or_insert(*count);
+                }
+            }
+        }
+
+        local_cache
+    }
+}
+
+fn parse_input(input: &str) -> Result<Stones, ParseError> {
+    let line = input.trim_end();
+    let stones = line
+        .split_whitespace()
+        .map(|stone| parse::number(line, stone))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(1))?;
+    Ok(Stones(stones))
+}
+
+fn solve_part_one(stones: &Stones) -> usize {
+    let mut stones = stones.clone();
+    (0..25).for_each(|_| stones = stones.blink());
+    stones.0.len()
+}
+
+fn solve_part_two(stones: &Stones, blinks: usize) -> u64 {
+    let mut cache: HashMap<u64, u64> = HashMap::new();
+
+    stones.0.iter().for_each(|stone| {
+        cache
+            .entry(*stone)
+            .and_modify(|v| {
+                *v = (Checked(*v) + 1).0;
+            })
+            .or_insert(1);
+    });
+
+    (0..blinks).for_each(|_| cache = stones.cached_blink(&cache));
+
+    cache.values().copied().sum::<Checked<u64>>().0
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const EXAMPLES: &'static [&'static str] = &["125 17"];
+    // blinking 75 times without counting equal stones does not fit in memory
+    const IMPLEMENTATIONS: &'static [(Part, &'static str, Solver<Self>)] = &[
+        (Part::One, "naive", Self::part_one),
+        (Part::One, "counted", |input| {
+            Ok(solve_part_two(input, 25).into())
+        }),
+        (Part::Two, "counted", Self::part_two),
+    ];
+
+    type Input<'a> = Stones;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input, 75).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "125 17";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 55312);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "125 17";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap(), 25);
+        assert_eq!(answer, 55312);
+    }
+}
@@ -0,0 +1,131 @@
+// Advent of Code - Day 12: Garden Groups
+
+use std::collections::HashSet;
+
+use aoc::coord::Coordinate;
+use aoc::grid::Grid;
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, Solution, SolveError};
+
+fn compute_perimeter(area: &HashSet<Coordinate<usize>>) -> usize {
+    let mut perimeter = 0;
+    for coord in area {
+        let neighbours = coord.adjacent();
+        for neighbour in neighbours {
+            match neighbour {
+                Some(coord) => {
+                    if !area.contains(&coord) {
+                        perimeter += 1
+                    }
+                }
+                None => perimeter += 1,
+            }
+        }
+    }
+    perimeter
+}
+
+fn compute_corners(area: &HashSet<Coordinate<usize>>) -> usize {
+    let mut corners = 0;
+
+    for &coord in area {
+        let surrounding = coord.surrounding();
+
+        // Check all 4 corner configurations using surrounding array indices
+        // surrounding: [up, topright, right, bottomright, down, bottomleft, left, topleft]
+        // adjacent: [up, right, down, left]
+        let corner_configs = [
+            (0, 6, 7), // up, left, topleft
+            (0, 2, 1), // up, right, topright
+            (4, 6, 5), // down, left, bottomleft
+            (4, 2, 3), // down, right, bottomright
+        ];
+        for &(adj1_idx, adj2_idx, diag_idx) in &corner_configs {
+            let has_adj1 = surrounding[adj1_idx].is_some_and(|c| area.contains(&c));
+            let has_adj2 = surrounding[adj2_idx].is_some_and(|c| area.contains(&c));
+            let has_diag = surrounding[diag_idx].is_some_and(|c| area.contains(&c));
+            // Outer corner: neither adjacent cell is in the region
+            // Inner corner: both adjacent cells are in the region, but diagonal is not
+            if (!has_adj1 && !has_adj2) || (has_adj1 && has_adj2 && !has_diag) {
+                corners += 1;
+            }
+        }
+    }
+    corners
+}
+
+/// the regions of connected garden plots with the same plant
+fn parse_input(input: &str) -> Vec<HashSet<Coordinate<usize>>> {
+    let grid = Grid::construct(input, &|c| c);
+    grid.regions(|a, b| a == b)
+}
+
+fn solve_part_one(regions: &[HashSet<Coordinate<usize>>]) -> usize {
+    regions
+        .iter()
+        .map(|area| area.len() * compute_perimeter(area))
+        .sum()
+}
+
+fn solve_part_two(regions: &[HashSet<Coordinate<usize>>]) -> usize {
+    regions
+        .iter()
+        .map(|area| area.len() * compute_corners(area))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input<'a> = Vec<HashSet<Coordinate<usize>>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input));
+        assert_eq!(answer, 1930);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input));
+        assert_eq!(answer, 1206);
+    }
+}
@@ -0,0 +1,170 @@
+// Advent of Code - Day 13: Claw Contraption
+use aoc::checked::Checked;
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Solution, SolveError};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Button {
+    dx: isize,
+    dy: isize,
+    cost: isize,
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    a: Button,
+    b: Button,
+    prize_x: isize,
+    prize_y: isize,
+}
+
+impl Machine {
+    fn solve(&self) -> Option<isize> {
+        let (a, b) = (self.a, self.b);
+        let determinant = (Checked(a.dx) * b.dy - Checked(b.dx) * a.dy).0;
+        if determinant == 0 {
+            // no solution
+            return None;
+        }
+
+        // find numerators (Da and Db)
+        let da = (Checked(self.prize_x) * b.dy - Checked(b.dx) * self.prize_y).0;
+        let db = (Checked(a.dx) * self.prize_y - Checked(self.prize_x) * a.dy).0;
+
+        // check for integer solutions
+        if da % determinant != 0 || db % determinant != 0 {
+            return None;
+        }
+
+        let presses_a = da / determinant;
+        let presses_b = db / determinant;
+
+        // check for non-negative solutions
+        if presses_a >= 0 && presses_b >= 0 {
+            Some((Checked(presses_a) * a.cost + Checked(presses_b) * b.cost).0)
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse::blocks(input)
+        .map(|block| {
+            let at_block = |e: ParseError| e.at_line(parse::lines_before(input, block) + 1);
+            let numbers: Vec<isize> = parse::ints(block).map_err(at_block)?;
+            if numbers.len() != 6 {
+                let expected = "two buttons and a prize, with 6 numbers";
+                return Err(at_block(ParseError::new(block, expected)));
+            }
+
+            Ok(Machine {
+                a: Button {
+                    dx: numbers[0],
+                    dy: numbers[1],
+                    cost: 3,
+                },
+                b: Button {
+                    dx: numbers[2],
+                    dy: numbers[3],
+                    cost: 1,
+                },
+                prize_x: numbers[4],
+                prize_y: numbers[5],
+            })
+        })
+        .collect()
+}
+
+fn solve_part_one(machines: &[Machine]) -> isize {
+    machines
+        .iter()
+        .filter_map(Machine::solve)
+        .sum::<Checked<isize>>()
+        .0
+}
+
+fn solve_part_two(machines: &[Machine]) -> isize {
+    // fix conversion error
+    let offset = 10_000_000_000_000;
+    let machines: Vec<Machine> = machines
+        .iter()
+        .map(|m| Machine {
+            a: m.a,
+            b: m.b,
+            prize_x: (Checked(m.prize_x) + offset).0,
+            prize_y: (Checked(m.prize_y) + offset).0,
+        })
+        .collect();
+    machines
+        .iter()
+        .filter_map(Machine::solve)
+        .sum::<Checked<isize>>()
+        .0
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 480);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 875318608908);
+    }
+}
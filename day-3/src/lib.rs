@@ -0,0 +1,103 @@
+// Advent of Code - Day 3: Mull It Over
+
+use aoc::combinator::{alt, delimited, literal, map, number, scan, separated_pair, value, Parser};
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, Solution, SolveError};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// parser for `mul(X,Y)`, `do()` and `don't()` instructions
+fn instruction<'a>() -> impl Parser<'a, Instruction> {
+    let mul = map(
+        delimited(
+            literal("mul("),
+            separated_pair(number(), literal(","), number()),
+            literal(")"),
+        ),
+        |(l, r)| Instruction::Mul(l, r),
+    );
+    let toggle = alt(
+        value(Instruction::Do, literal("do()")),
+        value(Instruction::Dont, literal("don't()")),
+    );
+    alt(mul, toggle)
+}
+
+/// every well-formed instruction in the corrupted memory, in order
+fn parse_input(input: &str) -> Vec<Instruction> {
+    scan(input, instruction()).collect()
+}
+
+fn solve_part_one(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(|&instruction| match instruction {
+            Instruction::Mul(x, y) => x * y,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn solve_part_two(instructions: &[Instruction]) -> u32 {
+    let mut enable_mul = true;
+    instructions
+        .iter()
+        .map(|&instruction| match instruction {
+            Instruction::Mul(x, y) if enable_mul => x * y,
+            Instruction::Mul(..) => 0,
+            Instruction::Do => {
+                enable_mul = true;
+                0
+            }
+            Instruction::Dont => {
+                enable_mul = false;
+                0
+            }
+        })
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input =
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input));
+        assert_eq!(answer, 161);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input));
+        assert_eq!(answer, 48);
+    }
+}
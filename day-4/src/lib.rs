@@ -0,0 +1,140 @@
+// Advent of Code - Day 4: Ceres Search
+
+use std::collections::HashMap;
+
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, Solution, SolveError};
+
+/// map input into grid, where coord (x,y) points to a char
+fn parse_input(input: &str) -> HashMap<(i32, i32), char> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(move |(x, value)| ((x as i32, y as i32), value))
+        })
+        .collect::<HashMap<(i32, i32), char>>()
+}
+
+fn solve_part_one(grid: &HashMap<(i32, i32), char>) -> u32 {
+    const DIRECTIONS: [(i32, i32); 8] = [
+        (0, 1),
+        (1, 0),
+        (0, -1),
+        (-1, 0),
+        (1, -1),
+        (-1, 1),
+        (1, 1),
+        (-1, -1),
+    ];
+
+    // starting at positions that contain 'X', look for 'MAS' in all directions
+    let pattern = ['M', 'A', 'S'];
+    grid.iter()
+        .filter(|(_, value)| **value == 'X')
+        .map(|(&idx, _value)| {
+            // iterate over directions and check grid for pattern match
+            DIRECTIONS
+                .iter()
+                .map(|&(dx, dy)| {
+                    let (mut local_x, mut local_y) = idx;
+                    // check if pattern matches for grid positions in given direction
+                    pattern.iter().all(|pattern_char| {
+                        local_x += dx;
+                        local_y += dy;
+                        grid.get(&(local_x, local_y)) == Some(pattern_char)
+                    })
+                })
+                // get number of pattern matches
+                .filter(|gotcha| *gotcha)
+                .count() as u32
+        })
+        .sum()
+}
+
+fn solve_part_two(grid: &HashMap<(i32, i32), char>) -> u32 {
+    const DIRECTIONS: [(i32, i32); 4] = [(1, -1), (-1, 1), (1, 1), (-1, -1)];
+
+    // starting at positions that contain 'A', and look if position is surrounded by 'M', 'A', 'S'
+    let pattern = ['M', 'A', 'S'];
+    grid.iter()
+        .filter(|(_, value)| **value == 'A')
+        // filter out when number of matches is not exactly 2
+        .filter(|(&idx, _value)| {
+            // iterate over diagonals and check grid for pattern match
+            DIRECTIONS
+                .iter()
+                .map(|&(dx, dy)| {
+                    // start by moving two steps in the opposite direction
+                    let (mut local_x, mut local_y) = (idx.0 - 2 * dx, idx.1 - 2 * dy);
+                    // check if pattern matches for grid positions in given direction
+                    pattern.iter().all(|pattern_char| {
+                        local_x += dx;
+                        local_y += dy;
+                        grid.get(&(local_x, local_y)) == Some(pattern_char)
+                    })
+                })
+                .filter(|gotcha| *gotcha)
+                .count()
+                == 2
+        })
+        .count() as u32
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input<'a> = HashMap<(i32, i32), char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input));
+        assert_eq!(answer, 18);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input));
+        assert_eq!(answer, 9);
+    }
+}
@@ -0,0 +1,220 @@
+// Advent of Code - Day 5: Print Queue
+
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Solution, SolveError};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pages: Vec<u32>,
+}
+
+impl Update {
+    /// an update of at least one page, or `None`
+    fn new(pages: Vec<u32>) -> Option<Self> {
+        (!pages.is_empty()).then_some(Update { pages })
+    }
+
+    /// return middle page number, which exists as an update is never empty
+    fn middle_page(&self) -> u32 {
+        self.pages[self.pages.len() / 2]
+    }
+
+    /// check if pages are sorted according to rules
+    fn is_sorted(&self, rules: &[&Rule]) -> bool {
+        rules.iter().all(|rule| rule.check(self))
+    }
+
+    /// apply the ordering of a rule to an update
+    fn apply_rule(&mut self, rule: &Rule) {
+        if let (Some(before_idx), Some(after_idx)) = (
+            self.pages.iter().position(|&p| p == rule.before),
+            self.pages.iter().position(|&p| p == rule.after),
+        ) {
+            let page = self.pages.remove(before_idx);
+            self.pages.insert(after_idx, page);
+        }
+    }
+
+    /// repeatedly apply rules that are vialoted untill update is correctly sorted
+    fn fix(&mut self, rules: &[Rule]) {
+        // filter rules that do not apply
+        let rules = rules
+            .iter()
+            .filter(|rule| self.pages.contains(&rule.before) && self.pages.contains(&rule.after))
+            .collect::<Vec<&Rule>>();
+
+        // apply violating rules until update is sorted according to all rules
+        while !self.is_sorted(&rules) {
+            for rule in &rules {
+                if !rule.check(self) {
+                    self.apply_rule(rule);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    before: u32,
+    after: u32,
+}
+
+impl Rule {
+    fn new(before: u32, after: u32) -> Self {
+        Self { before, after }
+    }
+
+    fn check(&self, update: &Update) -> bool {
+        if update.pages.contains(&self.before) && update.pages.contains(&self.after) {
+            let before_idx = update.pages.iter().position(|&page| page == self.before);
+            let after_idx = update.pages.iter().position(|&page| page == self.after);
+            return before_idx < after_idx;
+        };
+        true
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+    let mut blocks = parse::blocks(input);
+    let block = blocks.next().unwrap_or_default();
+    let rules = parse::pairs(block, "|")
+        .map_err(|e| e.after_lines(parse::lines_before(input, block)))?
+        .into_iter()
+        .map(|(before, after)| Rule::new(before, after))
+        .collect::<Vec<_>>();
+    let block = blocks.next().unwrap_or_default();
+    let updates = parse::lines(block, |l| {
+        Update::new(parse::ints::<u32>(l)?).ok_or_else(|| ParseError::new(l, "page numbers"))
+    })
+    .map_err(|e| e.after_lines(parse::lines_before(input, block)))?;
+    Ok((rules, updates))
+}
+
+fn solve_part_one((rules, updates): &(Vec<Rule>, Vec<Update>)) -> u32 {
+    updates
+        .iter()
+        // filter all correct updates
+        .filter(|update| rules.iter().all(|rule| rule.check(update)))
+        // sum of all middle pages for each correct update
+        .map(|update| update.middle_page())
+        .sum::<u32>()
+}
+
+fn solve_part_two((rules, updates): &(Vec<Rule>, Vec<Update>)) -> u32 {
+    // find all incorrect updates
+    let mut incorrect_updates: Vec<Update> = updates
+        .iter()
+        .filter(|update| rules.iter().any(|rule| !rule.check(update)))
+        .cloned()
+        .collect();
+    // fix each incorrect update
+    incorrect_updates.iter_mut().for_each(|update| {
+        update.fix(rules);
+    });
+    incorrect_updates
+        .iter()
+        .map(|update| update.middle_page())
+        .sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input<'a> = (Vec<Rule>, Vec<Update>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 143);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 123);
+    }
+
+    #[test]
+    fn empty_update() {
+        // an update without page numbers has no middle page
+        let err = crate::parse_input("47|53\n\n75,47\nnone\n").unwrap_err();
+        assert_eq!(err.line_number, Some(4));
+        assert_eq!(err.line, "none");
+    }
+}
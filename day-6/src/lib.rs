@@ -0,0 +1,232 @@
+// Advent of Code - Day 6: Guard Gallivant
+
+use std::collections::HashSet;
+
+use aoc::coord::Coordinate;
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, Solution, SolveError};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn turn(&mut self) {
+        *self = match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    height: usize,
+    width: usize,
+    obstacles: HashSet<Coordinate<usize>>,
+}
+
+fn parse_input(input: &str) -> Result<(Grid, Coordinate<usize>), ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let height = lines.len();
+    let width = lines.first().map_or(0, |line| line.len());
+    let mut guard_position = None;
+    let obstacles = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter_map(|(x, c)| match c {
+                    '^' => {
+                        guard_position = Some(Coordinate { x, y });
+                        None
+                    }
+                    '#' => Some(Coordinate { x, y }),
+                    _ => None,
+                })
+                .collect::<HashSet<Coordinate<_>>>()
+        })
+        .collect();
+    let guard_position =
+        guard_position.ok_or_else(|| ParseError::new("", "a guard `^` on the map"))?;
+    Ok((
+        Grid {
+            height,
+            width,
+            obstacles,
+        },
+        guard_position,
+    ))
+}
+
+fn patrol(
+    grid: &Grid,
+    start_position: &Coordinate<usize>,
+    start_direction: &Direction,
+) -> HashSet<Coordinate<usize>> {
+    let mut guard_position = *start_position;
+    let mut guard_direction = *start_direction;
+    let mut visited_positions = HashSet::new();
+    visited_positions.insert(guard_position);
+    loop {
+        let next_position = match &guard_direction {
+            Direction::Up => {
+                if guard_position.y == 0 {
+                    break;
+                }
+                guard_position - Coordinate { x: 0, y: 1 }
+            }
+            Direction::Right => {
+                if guard_position.x + 1 >= grid.width {
+                    break;
+                }
+                guard_position + Coordinate { x: 1, y: 0 }
+            }
+            Direction::Down => {
+                if guard_position.y + 1 >= grid.height {
+                    break;
+                }
+                guard_position + Coordinate { x: 0, y: 1 }
+            }
+            Direction::Left => {
+                if guard_position.x == 0 {
+                    break;
+                }
+                guard_position - Coordinate { x: 1, y: 0 }
+            }
+        };
+        if grid.obstacles.contains(&next_position) {
+            guard_direction.turn();
+        } else {
+            visited_positions.insert(next_position);
+            guard_position = next_position;
+        }
+    }
+    visited_positions
+}
+
+fn solve_part_one((grid, guard_position): &(Grid, Coordinate<usize>)) -> u32 {
+    let visited_positions = patrol(grid, guard_position, &Direction::Up);
+    visited_positions.len() as u32
+}
+
+fn solve_part_two((grid, guard_position): &(Grid, Coordinate<usize>)) -> u32 {
+    let guard_direction = Direction::Up;
+    let guard_position = *guard_position;
+    let mut visited_positions = patrol(grid, &guard_position, &guard_direction);
+    visited_positions.remove(&guard_position);
+    let new_obstacle_count = visited_positions
+        .iter()
+        .filter(|new_obstacle| {
+            let mut loop_position = guard_position;
+            let mut loop_direction = guard_direction;
+            let mut visisted_posdirs: HashSet<(Coordinate<usize>, Direction)> =
+                HashSet::from([(loop_position, loop_direction)]);
+            loop {
+                let next_position = match &loop_direction {
+                    Direction::Up => {
+                        if loop_position.y == 0 {
+                            break false;
+                        }
+                        loop_position - Coordinate { x: 0, y: 1 }
+                    }
+                    Direction::Right => {
+                        if loop_position.x + 1 >= grid.width {
+                            break false;
+                        }
+                        loop_position + Coordinate { x: 1, y: 0 }
+                    }
+                    Direction::Down => {
+                        if loop_position.y + 1 >= grid.height {
+                            break false;
+                        }
+                        loop_position + Coordinate { x: 0, y: 1 }
+                    }
+                    Direction::Left => {
+                        if loop_position.x == 0 {
+                            break false;
+                        }
+                        loop_position - Coordinate { x: 1, y: 0 }
+                    }
+                };
+                if grid.obstacles.contains(&next_position) || next_position == **new_obstacle {
+                    loop_direction.turn();
+                } else if visisted_posdirs.contains(&(next_position, loop_direction)) {
+                    break true;
+                } else {
+                    visisted_posdirs.insert((next_position, loop_direction));
+                    loop_position = next_position;
+                }
+            }
+        })
+        .count();
+    new_obstacle_count as u32
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input<'a> = (Grid, Coordinate<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 41);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn no_guard() {
+        assert!(crate::parse_input("..#\n...").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+// Advent of Code - Day 7: Bridge Repair
+
+use aoc::parse::{self, ParseError};
+use aoc::search::{ops, Op, Search};
+use aoc::solution::{Answer, Solution, SolveError};
+
+fn parse_into_equation(line: &str) -> Result<(u64, Vec<u64>), ParseError> {
+    let (test_value, numbers) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(line, "a test value followed by \":\""))?;
+    let test_value = parse::number(line, test_value)?;
+    let numbers = numbers
+        .split_whitespace()
+        .map(|num| parse::number(line, num))
+        .collect::<Result<_, _>>()?;
+    Ok((test_value, numbers))
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    parse::lines(input, parse_into_equation)
+}
+
+/// sum of the test values that can be produced from their numbers using `operators`
+fn calibration_result(equations: &[(u64, Vec<u64>)], operators: &[Op<u64>]) -> u64 {
+    // the operators only decrease the value when multiplying by zero, so without a zero operand
+    // stop once the test value is exceeded
+    let pruned = Search::new(operators).prune(|value, test_value| value > test_value);
+    let exhaustive = Search::new(operators);
+    equations
+        .iter()
+        .filter_map(|(test_value, numbers)| {
+            let search = if numbers.iter().skip(1).any(|&n| n == 0) {
+                &exhaustive
+            } else {
+                &pruned
+            };
+            search.solvable(numbers, *test_value).then_some(test_value)
+        })
+        .sum()
+}
+
+fn solve_part_one(equations: &[(u64, Vec<u64>)]) -> u64 {
+    calibration_result(equations, &[ops::ADD, ops::MUL])
+}
+
+fn solve_part_two(equations: &[(u64, Vec<u64>)]) -> u64 {
+    calibration_result(equations, &[ops::ADD, ops::MUL, ops::CONCAT])
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input<'a> = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 3749);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 11387);
+    }
+
+    #[test]
+    fn zero_operand() {
+        // both 5 + 3 and 5 * 3 exceed 6 before multiplying by 0 brings them back down
+        let answer = crate::solve_part_one(&crate::parse_input("6: 5 3 0 6").unwrap());
+        assert_eq!(answer, 6);
+    }
+}
@@ -0,0 +1,212 @@
+// Advent of Code - Day 9: Disk Fragmenter
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, Solution, SolveError};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Block {
+    File { id: usize, size: u32 },
+    Empty { size: u32 },
+}
+
+impl Block {
+    fn id(&self) -> Option<usize> {
+        match self {
+            Block::File { id, .. } => Some(*id),
+            Block::Empty { .. } => None,
+        }
+    }
+
+    fn size(&self) -> u32 {
+        match self {
+            Block::File { size, .. } => *size,
+            Block::Empty { size } => *size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiskMap {
+    blocks: Vec<Block>,
+}
+
+impl FromIterator<u32> for DiskMap {
+    fn from_iter<T: IntoIterator<Item = u32>>(iter: T) -> Self {
+        let mut id = 0;
+        let mut blocks = Vec::new();
+        for (i, size) in iter.into_iter().enumerate() {
+            if size > 0 {
+                if i % 2 == 0 {
+                    // files are at even indices
+                    blocks.push(Block::File { id, size });
+                    id += 1;
+                } else {
+                    blocks.push(Block::Empty { size })
+                }
+            }
+        }
+        Self { blocks }
+    }
+}
+
+impl DiskMap {
+    fn checksum(&self) -> u64 {
+        self.blocks
+            .iter()
+            .enumerate()
+            .fold(
+                (0_u64, 0_u64),
+                |(acc, current_pos), (_, segment)| match segment {
+                    Block::File { id, size } => {
+                        let new_pos = current_pos + (*size as u64);
+                        let sum =
+                            (current_pos..new_pos).fold(acc, |sum, pos| sum + pos * (*id as u64));
+                        (sum, new_pos)
+                    }
+                    Block::Empty { size } => (acc, current_pos + (*size as u64)),
+                },
+            )
+            .0
+    }
+
+    fn max_id(&self) -> Option<usize> {
+        self.blocks.iter().filter_map(|b| b.id()).max()
+    }
+
+    fn find_idx(&self, target_id: usize) -> Option<usize> {
+        self.blocks
+            .iter()
+            .position(|b| matches!(b, Block::File { id, .. } if *id == target_id))
+    }
+
+    /// shrink the block at `idx` by `amount`, at most its size, removing it once nothing is left
+    fn reduce_segment(&mut self, idx: usize, amount: u32) {
+        match &mut self.blocks[idx] {
+            Block::Empty { size } | Block::File { size, .. } => {
+                debug_assert!(
+                    amount <= *size,
+                    "cannot reduce block {idx} of size {size} by {amount}"
+                );
+                if amount < *size {
+                    *size -= amount;
+                } else {
+                    self.blocks.remove(idx);
+                }
+            }
+        }
+    }
+
+    fn compress(&mut self) {
+        let mut block_idx = 0;
+        while block_idx < self.blocks.len() {
+            if let Block::Empty { size } = self.blocks[block_idx] {
+                for _ in 0..size {
+                    self.reduce_segment(block_idx, 1);
+                    if let Some((last_file_idx, id)) = self
+                        .blocks
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .find_map(|(idx, block)| Some((idx, block.id()?)))
+                    {
+                        if last_file_idx < block_idx {
+                            break;
+                        }
+                        self.reduce_segment(last_file_idx, 1);
+                        self.blocks.insert(block_idx, Block::File { id, size: 1 });
+                        block_idx += 1;
+                    }
+                }
+            }
+            block_idx += 1;
+        }
+    }
+
+    fn compressv2(&mut self) {
+        let Some(max_id) = self.max_id() else {
+            return;
+        };
+        for file_id in (0..=max_id).rev() {
+            let Some(file_idx) = self.find_idx(file_id) else {
+                continue;
+            };
+            if let Some(empty_idx) = self.blocks[..file_idx].iter().position(|b| {
+                matches!(b, Block::Empty { .. }) && b.size() >= self.blocks[file_idx].size()
+            }) {
+                let file = self.blocks.remove(file_idx);
+                self.blocks
+                    .insert(file_idx, Block::Empty { size: file.size() });
+                self.blocks.insert(empty_idx, file);
+                self.reduce_segment(empty_idx + 1, file.size());
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<DiskMap, ParseError> {
+    let line = input.trim_end();
+    line.chars()
+        .map(|c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(line, "a disk map of digits").at_line(1))
+        })
+        .collect()
+}
+
+fn solve_part_one(disk_map: &DiskMap) -> u64 {
+    let mut disk_map = disk_map.clone();
+    disk_map.compress();
+    disk_map.checksum()
+}
+
+fn solve_part_two(disk_map: &DiskMap) -> u64 {
+    let mut disk_map = disk_map.clone();
+    disk_map.compressv2();
+    disk_map.checksum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input<'a> = DiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "2333133121414131402";
+        let answer = crate::solve_part_one(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 1928);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "2333133121414131402";
+        let answer = crate::solve_part_two(&crate::parse_input(example_input).unwrap());
+        assert_eq!(answer, 2858);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(crate::parse_input("2333133121414131402\n").is_ok());
+        let err = crate::parse_input("23331x3").unwrap_err();
+        assert_eq!(err.line_number, Some(1));
+        assert!(crate::parse_input("12\n34").is_err());
+        assert_eq!(crate::solve_part_two(&crate::parse_input("").unwrap()), 0);
+    }
+}
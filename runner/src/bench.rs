@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use aoc::solution::{Day, Part, Run};
+
+/// Summary of repeated timings
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let secs = samples
+            .iter()
+            .map(Duration::as_secs_f64)
+            .collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+        Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings of one day over several runs
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+    pub total: Stats,
+}
+
+impl Report {
+    pub fn new(year: u32, day: u32, runs: &[Run]) -> Report {
+        let stats =
+            |time: fn(&Run) -> Duration| Stats::of(&runs.iter().map(time).collect::<Vec<_>>());
+        Report {
+            year,
+            day,
+            parse: stats(|run| run.parse),
+            part_one: stats(|run| run.time(Part::One)),
+            part_two: stats(|run| run.time(Part::Two)),
+            total: stats(Run::total),
+        }
+    }
+}
+
+/// Call `run` for `day` untimed a few times to warm up caches, then `runs` times while measuring.
+/// A single run is not warmed up, so that a plain `aoc run` solves every day once. Returns the
+/// last run for its answers, or the first error.
+pub fn bench<E>(
+    day: &Day,
+    runs: usize,
+    run: impl Fn() -> Result<Run, E>,
+) -> Result<(Run, Report), E> {
+    let warm_up = if runs > 1 { runs.div_ceil(10) } else { 0 };
+    for _ in 0..warm_up {
+        run()?;
+    }
+    let runs = (0..runs.max(1))
+        .map(|_| run())
+        .collect::<Result<Vec<_>, _>>()?;
+    let report = Report::new(day.year, day.day, &runs);
+    Ok((runs.into_iter().next_back().unwrap(), report))
+}
+
+/// human readable duration with three significant digits, e.g. `12.3µs`
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = match nanos {
+        n if n < 1e3 => return format!("{n}ns"),
+        n if n < 1e6 => (n / 1e3, "µs"),
+        n if n < 1e9 => (n / 1e6, "ms"),
+        n => (n / 1e9, "s"),
+    };
+    significant(value, unit)
+}
+
+/// human readable size with three significant digits, e.g. `1.50KiB`
+pub fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    let (value, unit) = match bytes {
+        b if b < 1024.0 => return format!("{b}B"),
+        b if b < 1024.0 * 1024.0 => (b / 1024.0, "KiB"),
+        b if b < 1024.0 * 1024.0 * 1024.0 => (b / (1024.0 * 1024.0), "MiB"),
+        b => (b / (1024.0 * 1024.0 * 1024.0), "GiB"),
+    };
+    significant(value, unit)
+}
+
+fn significant(value: f64, unit: &str) -> String {
+    match value {
+        v if v < 10.0 => format!("{v:.2}{unit}"),
+        v if v < 100.0 => format!("{v:.1}{unit}"),
+        v => format!("{v:.0}{unit}"),
+    }
+}
+
+/// per-day table of mean timings, followed by the total for the year
+pub fn print_table(reports: &[Report]) {
+    println!(
+        "{:>4}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}",
+        "Day", "Parse", "Part one", "Part two", "Total", "Median", "Std dev"
+    );
+    for report in reports {
+        println!(
+            "{:>4}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}",
+            report.day,
+            format_duration(report.parse.mean),
+            format_duration(report.part_one.mean),
+            format_duration(report.part_two.mean),
+            format_duration(report.total.mean),
+            format_duration(report.total.median),
+            format_duration(report.total.std_dev),
+        );
+    }
+    // days are independent, so their means, medians and variances add up
+    let sum = |stats: fn(&Report) -> Duration| reports.iter().map(stats).sum::<Duration>();
+    let variance = reports
+        .iter()
+        .map(|r| r.total.std_dev.as_secs_f64().powi(2))
+        .sum::<f64>();
+    println!(
+        "{:>4}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}",
+        "Year",
+        format_duration(sum(|r| r.parse.mean)),
+        format_duration(sum(|r| r.part_one.mean)),
+        format_duration(sum(|r| r.part_two.mean)),
+        format_duration(sum(|r| r.total.mean)),
+        format_duration(sum(|r| r.total.median)),
+        format_duration(Duration::from_secs_f64(variance.sqrt())),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = |n| Duration::from_millis(n);
+        let stats = Stats::of(&[ms(2), ms(4), ms(4), ms(4), ms(5), ms(5), ms(7), ms(9)]);
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.std_dev, ms(2));
+        assert_eq!(Stats::of(&[ms(3), ms(1), ms(2)]).median, ms(2));
+        assert_eq!(Stats::of(&[]), Stats::default());
+    }
+
+    #[test]
+    fn test_bench_warm_up() {
+        let day = Day::of::<day_1::Day1>();
+        for (runs, calls) in [(1, 1), (10, 11), (20, 22)] {
+            let count = std::cell::Cell::new(0);
+            let (_, report) = bench(&day, runs, || {
+                count.set(count.get() + 1);
+                Ok::<_, String>(Run::new(Duration::from_millis(1), Default::default()))
+            })
+            .unwrap();
+            assert_eq!(count.get(), calls);
+            assert_eq!(report.parse.median, Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::ZERO), "0ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_234)), "1.23s");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1000), "1000B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(200 * 1024 * 1024), "200MiB");
+        assert_eq!(format_bytes(3 << 30), "3.00GiB");
+    }
+}
@@ -0,0 +1,249 @@
+//! HTTP client for the Advent of Code website.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc::solution::{Answer, Part};
+
+use crate::config::Config;
+use crate::submit::{self, Verdict};
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client, String> {
+        let session = config.session.clone().ok_or(
+            "no session token, set AOC_SESSION or `session` in the config file \
+             to the session cookie of your browser",
+        )?;
+        let user_agent = config.user_agent.as_deref().ok_or(
+            "no contact for the User-Agent header, set AOC_CONTACT or `contact` in the config \
+             file to an email address or other way to reach you",
+        )?;
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Ok(Client {
+            agent,
+            base_url: config.base_url.clone(),
+            session,
+        })
+    }
+
+    /// the personal puzzle input of `day` of `year`
+    pub fn input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("cannot read the input of day {day} of {year}: {e}")),
+            Err(ureq::Error::Status(404, _)) => Err(format!(
+                "the input of day {day} of {year} is not available yet"
+            )),
+            Err(ureq::Error::Status(400 | 401 | 403 | 500, _)) => Err(format!(
+                "the input of day {day} of {year} was refused, is the session token still valid?"
+            )),
+            Err(e) => Err(format!(
+                "cannot download the input of day {day} of {year}: {e}"
+            )),
+        }
+    }
+}
+
+impl Client {
+    /// Submit `answer` for `part` of `day` of `year`, returning the verdict and how long to wait
+    /// before the next submission
+    pub fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<(Verdict, Duration), String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", &answer.to_string())])
+            .map_err(|e| format!("cannot submit the answer of day {day} of {year}: {e}"))?;
+        let page = response
+            .into_string()
+            .map_err(|e| format!("cannot read the verdict for day {day} of {year}: {e}"))?;
+        submit::parse_response(&page)
+            .ok_or_else(|| format!("unexpected response to the answer of day {day} of {year}"))
+    }
+}
+
+/// Download the input of `day` of `year` into the inputs directory `dir`, unless it is already
+/// there. Returns the path of the input and whether it was downloaded.
+pub fn fetch_input(
+    config: &Config,
+    dir: &Path,
+    year: u32,
+    day: u32,
+) -> Result<(PathBuf, bool), String> {
+    let path = aoc::input::path_in(dir, year, day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+    let input = Client::new(config)?.input(year, day)?;
+    // write next to the input and rename, so that an interrupted download leaves no partial input
+    let partial = path.with_extension(format!("txt.{}.partial", std::process::id()));
+    fs::create_dir_all(path.parent().unwrap_or(dir))
+        .and_then(|()| fs::write(&partial, input))
+        .and_then(|()| fs::rename(&partial, &path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&partial);
+            format!(
+                "cannot save the input of day {day} of {year} to {}: {e}",
+                path.display()
+            )
+        })?;
+    Ok((path, true))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+
+    /// Serve a single request on a local port, answering with `status` and `body`. Joining the
+    /// handle returns the request line and headers.
+    pub fn stub_server(status: u16, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length: ") {
+                    content_length = length.parse().unwrap();
+                }
+                request.push(line);
+            }
+            let mut content = vec![0; content_length];
+            std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+            request.push(String::from_utf8(content).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    pub fn config(base_url: String) -> Config {
+        Config {
+            session: Some("abc123".to_string()),
+            base_url,
+            user_agent: Some("aoc-test".to_string()),
+            hash_answers: false,
+        }
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = stub_server(200, "3   4\n4   3\n");
+        let client = Client::new(&config(url)).unwrap();
+        assert_eq!(client.input(2023, 1).unwrap(), "3   4\n4   3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+        assert!(request.contains(&"Cookie: session=abc123".to_string()));
+        assert!(request
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("user-agent: aoc-test")));
+    }
+
+    #[test]
+    fn test_fetch_input_once() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let (url, server) = stub_server(200, "xmul(2,4)");
+        let (path, downloaded) = fetch_input(&config(url.clone()), &dir, 2024, 3).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "xmul(2,4)");
+        server.join().unwrap();
+
+        // the stub server is gone, so this only works from the cache
+        assert_eq!(
+            fetch_input(&config(url), &dir, 2024, 3).unwrap(),
+            (path.clone(), false)
+        );
+        // nothing but the input is left behind
+        let files = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert_eq!(files, vec![path]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_no_contact() {
+        let config = Config {
+            user_agent: None,
+            ..config(crate::config::DEFAULT_BASE_URL.to_string())
+        };
+        let err = Client::new(&config).err().unwrap();
+        assert!(err.contains("AOC_CONTACT"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = stub_server(
+            200,
+            "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+        );
+        let client = Client::new(&config(url)).unwrap();
+        let (verdict, _) = client
+            .submit(2024, 7, Part::Two, &Answer::Number(11387))
+            .unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "POST /2024/day/7/answer HTTP/1.1");
+        assert_eq!(request.last().unwrap(), "level=2&answer=11387");
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (url, server) = stub_server(404, "not found");
+        let client = Client::new(&config(url)).unwrap();
+        assert_eq!(
+            client.input(2024, 25).unwrap_err(),
+            "the input of day 25 of 2024 is not available yet"
+        );
+        server.join().unwrap();
+
+        let mut no_session = config(String::new());
+        no_session.session = None;
+        assert!(Client::new(&no_session).is_err());
+    }
+}
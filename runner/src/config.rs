@@ -0,0 +1,144 @@
+//! Settings for talking to the Advent of Code website.
+//!
+//! Every setting is read from an environment variable first, and otherwise from the config file
+//! (`$AOC_CONFIG`, or `aoc/config` in `$XDG_CONFIG_HOME` or `~/.config`), which holds
+//! `key = value` lines:
+//!
+//! ```text
+//! session = 53616c7465645f5f...
+//! contact = me@example.com
+//! hash_answers = true
+//! ```
+//!
+//! Requests identify themselves with the repository and the `contact`, as the website asks, or
+//! with a `user_agent` of your own.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// where the requests come from, in the default user agent
+pub const REPOSITORY: &str = "github.com/jeroen-rooijmans/advent-of-code-2024-rust";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// `user_agent`, or the repository and `contact`; there is no default without a contact
+    pub user_agent: Option<String>,
+    /// store hashes instead of plain answers in the answers ledger
+    pub hash_answers: bool,
+}
+
+impl Config {
+    pub fn load() -> Result<Config, String> {
+        let file = match config_path() {
+            Some(path) if path.exists() => {
+                let content = fs::read_to_string(&path)
+                    .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+                parse(&content).map_err(|e| format!("{}: {e}", path.display()))?
+            }
+            _ => HashMap::new(),
+        };
+        Ok(Config::from_settings(|key| {
+            std::env::var(format!("AOC_{}", key.to_uppercase()))
+                .ok()
+                .or_else(|| file.get(key).cloned())
+        }))
+    }
+
+    fn from_settings(setting: impl Fn(&str) -> Option<String>) -> Config {
+        Config {
+            session: setting("session").filter(|s| !s.is_empty()),
+            base_url: setting("base_url")
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            user_agent: setting("user_agent")
+                .or_else(|| setting("contact").map(|contact| format!("{REPOSITORY} by {contact}")))
+                .filter(|agent| !agent.is_empty()),
+            hash_answers: setting("hash_answers").is_some_and(|v| v == "true" || v == "1"),
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(dir.join("aoc").join("config"))
+}
+
+/// `key = value` lines, ignoring blank lines and `#` comments
+fn parse(content: &str) -> Result<HashMap<String, String>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            Ok((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let settings = parse(
+            "# token
+session = \"abc123\"
+
+base_url=http://localhost:8080/
+contact = me@example.com
+hash_answers = true
+",
+        )
+        .unwrap();
+        assert_eq!(settings["session"], "abc123");
+
+        let config = Config::from_settings(|key| settings.get(key).cloned());
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(
+            config.user_agent.as_deref(),
+            Some("github.com/jeroen-rooijmans/advent-of-code-2024-rust by me@example.com")
+        );
+        assert!(config.hash_answers);
+
+        assert_eq!(
+            parse("session abc").unwrap_err(),
+            "line 1: expected `key = value`"
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::from_settings(|_| None);
+        assert_eq!(config.session, None);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.user_agent, None);
+        assert!(!config.hash_answers);
+
+        let config = Config::from_settings(|key| {
+            (key == "user_agent" || key == "contact").then(|| key.to_string())
+        });
+        assert_eq!(config.user_agent.as_deref(), Some("user_agent"));
+    }
+}
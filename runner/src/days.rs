@@ -0,0 +1,65 @@
+use aoc::solution::Day;
+
+/// every solved day, ordered by year and day
+pub const DAYS: &[Day] = &[
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_3::Day3>(),
+    Day::of::<day_4::Day4>(),
+    Day::of::<day_5::Day5>(),
+    Day::of::<day_6::Day6>(),
+    Day::of::<day_7::Day7>(),
+    Day::of::<day_8::Day8>(),
+    Day::of::<day_9::Day9>(),
+    Day::of::<day_10::Day10>(),
+    Day::of::<day_11::Day11>(),
+    Day::of::<day_12::Day12>(),
+    Day::of::<day_13::Day13>(),
+    Day::of::<day_14::Day14>(),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| (d.year, d.day) == (year, day))
+}
+
+/// every solved day of `year`
+pub fn of_year(year: u32) -> Vec<&'static Day> {
+    DAYS.iter().filter(|d| d.year == year).collect()
+}
+
+/// `day` of `year`, or an error saying it has no solution
+pub fn get(year: u32, day: u32) -> Result<&'static Day, String> {
+    find(year, day).ok_or_else(|| format!("day {day} of {year} is not solved yet"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::solution::{Answer, Part, DEFAULT_YEAR};
+
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        assert!(DAYS
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert_eq!(
+            find(DEFAULT_YEAR, 7).map(|d| d.title),
+            Some("Bridge Repair")
+        );
+        assert!(find(DEFAULT_YEAR, 25).is_none());
+        assert!(find(2015, 7).is_none());
+        assert_eq!(of_year(DEFAULT_YEAR).len(), DAYS.len());
+    }
+
+    #[test]
+    fn test_select_falls_back_to_default() {
+        // day 11 only blinks naively in part one, which `--impl naive` must not fail on
+        let day = find(DEFAULT_YEAR, 11).unwrap();
+        let parts = day.select(&Part::ALL, "naive");
+        assert_eq!(parts, [(Part::One, "naive"), (Part::Two, "counted")]);
+        let run = (day.run_with)(day.examples[0], &parts).unwrap().unwrap();
+        assert_eq!(run.parts[0].1, Answer::Number(55312));
+        assert_eq!(run.parts.len(), 2);
+    }
+}
@@ -0,0 +1,401 @@
+//! Benchmark history, one tab separated record per day and stage, appended after each benchmark.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::solution::{Part, DEFAULT_IMPLEMENTATION, DEFAULT_YEAR};
+
+use crate::bench::{Report, Stats};
+
+pub const DEFAULT_PATH: &str = ".aoc/bench-history.tsv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+    Total,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 4] = [Stage::Parse, Stage::PartOne, Stage::PartTwo, Stage::Total];
+
+    fn name(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part1",
+            Stage::PartTwo => "part2",
+            Stage::Total => "total",
+        }
+    }
+
+    fn stats(self, report: &Report) -> Stats {
+        match self {
+            Stage::Parse => report.parse,
+            Stage::PartOne => report.part_one,
+            Stage::PartTwo => report.part_two,
+            Stage::Total => report.total,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Where and when a benchmark ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    pub timestamp: u64,
+    pub commit: String,
+    pub machine: String,
+}
+
+impl Environment {
+    /// the current time, git commit (`-dirty` with uncommitted changes) and machine name
+    pub fn current() -> Environment {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let commit = match git(&["rev-parse", "--short", "HEAD"]) {
+            Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+                format!("{commit}-dirty")
+            }
+            Some(commit) => commit,
+            None => "unknown".to_string(),
+        };
+        Environment {
+            timestamp,
+            commit,
+            machine: machine(),
+        }
+    }
+}
+
+/// `AOC_MACHINE`, or else the host name
+fn machine() -> String {
+    std::env::var("AOC_MACHINE")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Timings of one stage of one day, as stored in the history
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub environment: Environment,
+    pub year: u32,
+    pub day: u32,
+    pub stage: Stage,
+    /// name given to `--impl`, or the default implementation
+    pub implementation: String,
+    /// parts that ran, which the total is the time of
+    pub parts: Vec<Part>,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+/// What a record measured: the year, day, stage, implementation and parts. Only records with the
+/// same key are compared.
+pub type Key<'a> = (u32, u32, Stage, &'a str, &'a [Part]);
+
+impl Record {
+    /// records of every stage that ran
+    pub fn from_report(
+        environment: &Environment,
+        report: &Report,
+        runs: usize,
+        implementation: &str,
+        parts: &[Part],
+    ) -> Vec<Record> {
+        Stage::ALL
+            .into_iter()
+            .map(|stage| Record {
+                environment: environment.clone(),
+                year: report.year,
+                day: report.day,
+                stage,
+                implementation: implementation.to_string(),
+                parts: parts.to_vec(),
+                runs,
+                stats: stage.stats(report),
+            })
+            .filter(|record| record.stats.mean > Duration::ZERO)
+            .collect()
+    }
+
+    pub fn key(&self) -> Key<'_> {
+        (
+            self.year,
+            self.day,
+            self.stage,
+            &self.implementation,
+            &self.parts,
+        )
+    }
+
+    fn to_line(&self) -> String {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| part.number().to_string())
+            .collect::<Vec<_>>();
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.environment.timestamp,
+            self.environment.commit,
+            self.environment.machine,
+            self.year,
+            self.day,
+            self.stage,
+            self.implementation,
+            parts.join(","),
+            self.runs,
+            self.stats.mean.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.std_dev.as_nanos(),
+        )
+    }
+
+    fn parse(line: &str) -> Option<Record> {
+        let mut fields = line.split('\t').collect::<Vec<_>>();
+        // records without a year are of the default year, and those without an implementation and
+        // parts ran the default implementation of both parts
+        let year = match fields.len() {
+            10 | 12 => fields.remove(3).parse().ok()?,
+            _ => DEFAULT_YEAR,
+        };
+        let (implementation, parts) = match fields.len() {
+            11 => (fields.remove(5), fields.remove(5)),
+            _ => (DEFAULT_IMPLEMENTATION, "1,2"),
+        };
+        let &[timestamp, commit, machine, day, stage, runs, mean, median, std_dev] =
+            fields.as_slice()
+        else {
+            return None;
+        };
+        let parts = parts
+            .split(',')
+            .map(|part| Part::from_number(part.parse().ok()?))
+            .collect::<Option<_>>()?;
+        let nanos = |s: &str| s.parse().ok().map(Duration::from_nanos);
+        Some(Record {
+            environment: Environment {
+                timestamp: timestamp.parse().ok()?,
+                commit: commit.to_string(),
+                machine: machine.to_string(),
+            },
+            year,
+            day: day.parse().ok()?,
+            stage: Stage::ALL.into_iter().find(|s| s.name() == stage)?,
+            implementation: implementation.to_string(),
+            parts,
+            runs: runs.parse().ok()?,
+            stats: Stats {
+                mean: nanos(mean)?,
+                median: nanos(median)?,
+                std_dev: nanos(std_dev)?,
+            },
+        })
+    }
+}
+
+/// history file given on the command line, or the default one
+pub fn path(path: Option<PathBuf>) -> PathBuf {
+    path.unwrap_or_else(|| PathBuf::from(DEFAULT_PATH))
+}
+
+/// all records in the history, an empty history if the file does not exist
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                Record::parse(line).ok_or_else(|| {
+                    format!("{}:{}: invalid benchmark record", path.display(), i + 1)
+                })
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+    }
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+    let write = || -> io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for record in records {
+            writeln!(file, "{}", record.to_line())?;
+        }
+        Ok(())
+    };
+    write().map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// Latest record of every day, stage, implementation and parts measured on `machine`, optionally
+/// only from `commit`
+pub fn baseline<'a>(
+    history: &'a [Record],
+    machine: &str,
+    commit: Option<&str>,
+) -> BTreeMap<Key<'a>, &'a Record> {
+    history
+        .iter()
+        .filter(|r| r.environment.machine == machine)
+        .filter(|r| commit.is_none_or(|c| r.environment.commit == c))
+        .map(|r| (r.key(), r))
+        .collect()
+}
+
+/// Median time of a day and stage before and after a change
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: u32,
+    pub day: u32,
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Change {
+    /// relative change in percent, positive when slower
+    pub fn percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.percent() > threshold_percent
+    }
+}
+
+/// changes of every record in `current` that has a baseline with the same key
+pub fn compare(baseline: &BTreeMap<Key, &Record>, current: &[Record]) -> Vec<Change> {
+    current
+        .iter()
+        .filter_map(|record| {
+            let before = baseline.get(&record.key())?;
+            (before.stats.median > Duration::ZERO).then_some(Change {
+                year: record.year,
+                day: record.day,
+                stage: record.stage,
+                before: before.stats.median,
+                after: record.stats.median,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, machine: &str, day: u32, stage: Stage, median_us: u64) -> Record {
+        let median = Duration::from_micros(median_us);
+        Record {
+            environment: Environment {
+                timestamp: 1733011200,
+                commit: commit.to_string(),
+                machine: machine.to_string(),
+            },
+            year: DEFAULT_YEAR,
+            day,
+            stage,
+            implementation: DEFAULT_IMPLEMENTATION.to_string(),
+            parts: Part::ALL.to_vec(),
+            runs: 10,
+            stats: Stats {
+                mean: median,
+                median,
+                std_dev: Duration::from_nanos(120),
+            },
+        }
+    }
+
+    #[test]
+    fn test_line_roundtrip() {
+        let record = record("8be9d14", "laptop", 7, Stage::PartTwo, 1500);
+        assert_eq!(
+            record.to_line(),
+            "1733011200\t8be9d14\tlaptop\t2024\t7\tpart2\tdefault\t1,2\t10\t1500000\t1500000\t120"
+        );
+        assert_eq!(Record::parse(&record.to_line()), Some(record.clone()));
+        assert_eq!(
+            Record::parse("1733011200\t8be9d14\tlaptop\t2024\t7\tpart2\t10\t1500000\t1500000\t120"),
+            Some(record.clone())
+        );
+        assert_eq!(
+            Record::parse("1733011200\t8be9d14\tlaptop\t7\tpart2\t10\t1500000\t1500000\t120"),
+            Some(record.clone())
+        );
+        let naive = Record {
+            implementation: "naive".to_string(),
+            parts: vec![Part::Two],
+            ..record
+        };
+        assert!(naive.to_line().contains("\tpart2\tnaive\t2\t10\t"));
+        assert_eq!(Record::parse(&naive.to_line()), Some(naive));
+        assert_eq!(Record::parse("1733011200\t8be9d14"), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = vec![
+            record("aaa", "laptop", 7, Stage::Total, 100),
+            record("bbb", "laptop", 7, Stage::Total, 200),
+            record("bbb", "desktop", 7, Stage::Total, 50),
+            record("bbb", "laptop", 12, Stage::Total, 1000),
+        ];
+        let current = vec![
+            record("ccc", "laptop", 7, Stage::Total, 230),
+            record("ccc", "laptop", 12, Stage::Total, 900),
+            record("ccc", "laptop", 13, Stage::Total, 10),
+        ];
+
+        let changes = compare(&baseline(&history, "laptop", None), &current);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].before, Duration::from_micros(200));
+        assert!((changes[0].percent() - 15.0).abs() < 1e-9);
+        assert!(changes[0].is_regression(10.0));
+        assert!(!changes[0].is_regression(20.0));
+        assert!(!changes[1].is_regression(0.0));
+
+        let changes = compare(&baseline(&history, "laptop", Some("aaa")), &current);
+        assert_eq!(changes.len(), 1);
+        assert!((changes[0].percent() - 130.0).abs() < 1e-9);
+
+        // only runs of the same implementation and parts are compared
+        let naive = Record {
+            implementation: "naive".to_string(),
+            ..record("ddd", "laptop", 7, Stage::Total, 5000)
+        };
+        let part_one = Record {
+            parts: vec![Part::One],
+            ..record("ddd", "laptop", 7, Stage::Total, 50)
+        };
+        let changes = compare(&baseline(&history, "laptop", None), &[naive, part_one]);
+        assert!(changes.is_empty());
+    }
+}
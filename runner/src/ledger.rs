@@ -0,0 +1,188 @@
+//! Accepted answers, so that every solved day can be checked against its real input.
+//!
+//! One `year day part answer` line per solved part, tab separated; lines without a year belong to
+//! the default year. Answers can be stored as a SHA-256 hash (`sha256:` followed by the hex
+//! digest) instead, to keep them out of a public repository.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use aoc::solution::{Answer, Part, DEFAULT_YEAR};
+
+pub const DEFAULT_PATH: &str = "answers.tsv";
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// Accepted answer, in plain text or hashed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    Hashed(String),
+}
+
+impl Expected {
+    pub fn new(year: u32, day: u32, part: Part, answer: &Answer, hashed: bool) -> Expected {
+        if hashed {
+            Expected::Hashed(hash(year, day, part, answer))
+        } else {
+            Expected::Plain(answer.to_string())
+        }
+    }
+
+    pub fn matches(&self, year: u32, day: u32, part: Part, answer: &Answer) -> bool {
+        match self {
+            Expected::Plain(expected) => *expected == answer.to_string(),
+            Expected::Hashed(expected) => *expected == hash(year, day, part, answer),
+        }
+    }
+}
+
+impl std::fmt::Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Plain(answer) => f.write_str(answer),
+            Expected::Hashed(hash) => write!(f, "{HASH_PREFIX}{hash}"),
+        }
+    }
+}
+
+/// the answer is hashed together with the puzzle, so equal answers of different parts differ
+fn hash(year: u32, day: u32, part: Part, answer: &Answer) -> String {
+    let digest = Sha256::digest(format!("{year}/{day}/{}/{answer}", part.number()));
+    digest.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02x}").unwrap();
+        hex
+    })
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<(u32, u32, Part), Expected>,
+}
+
+impl Ledger {
+    /// the ledger in `path`, an empty one if the file does not exist
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Ledger::default()),
+            Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+        };
+        let answers = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                parse_line(line).ok_or_else(|| {
+                    format!(
+                        "{}:{}: expected `year day part answer`",
+                        path.display(),
+                        i + 1
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Ledger { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut content = String::new();
+        for ((year, day, part), expected) in &self.answers {
+            writeln!(content, "{year}\t{day}\t{}\t{expected}", part.number()).unwrap();
+        }
+        fs::write(path, content).map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&Expected> {
+        self.answers.get(&(year, day, part))
+    }
+
+    /// whether the ledger stores hashes, so that new answers are stored the same way
+    pub fn is_hashed(&self) -> bool {
+        self.answers
+            .values()
+            .any(|expected| matches!(expected, Expected::Hashed(_)))
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: Part, expected: Expected) {
+        self.answers.insert((year, day, part), expected);
+    }
+}
+
+fn parse_line(line: &str) -> Option<((u32, u32, Part), Expected)> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let (year, day, part, answer) = match *fields.as_slice() {
+        [year, day, part, answer] => (year.parse().ok()?, day, part, answer),
+        [day, part, answer] => (DEFAULT_YEAR, day, part, answer),
+        _ => return None,
+    };
+    let day = day.parse().ok()?;
+    let part = Part::from_number(part.parse().ok()?)?;
+    let expected = match answer.strip_prefix(HASH_PREFIX) {
+        Some(hash) => Expected::Hashed(hash.to_string()),
+        None => Expected::Plain(answer.to_string()),
+    };
+    Some(((year, day, part), expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let answer = Answer::Number(3749);
+        let plain = Expected::new(2024, 7, Part::One, &answer, false);
+        let hashed = Expected::new(2024, 7, Part::One, &answer, true);
+        assert_eq!(plain.to_string(), "3749");
+        assert!(hashed.to_string().starts_with("sha256:"));
+        assert_eq!(hashed.to_string().len(), 7 + 64);
+
+        assert!(plain.matches(2024, 7, Part::One, &answer));
+        assert!(hashed.matches(2024, 7, Part::One, &answer));
+        assert!(!hashed.matches(2024, 7, Part::One, &Answer::Number(3748)));
+        assert!(!hashed.matches(2024, 7, Part::Two, &answer));
+        assert!(!hashed.matches(2023, 7, Part::One, &answer));
+    }
+
+    #[test]
+    fn test_load_save() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.tsv", std::process::id()));
+        let mut ledger = Ledger::default();
+        ledger.insert(2024, 12, Part::Two, Expected::Plain("1206".to_string()));
+        ledger.insert(
+            2023,
+            7,
+            Part::One,
+            Expected::new(2023, 7, Part::One, &Answer::Number(3749), true),
+        );
+        ledger.save(&path).unwrap();
+
+        let loaded = Ledger::load(&path).unwrap();
+        assert_eq!(loaded, ledger);
+        assert!(loaded.is_hashed());
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .starts_with("2023\t7\t1\tsha256:"));
+
+        // lines without a year are of the default year
+        fs::write(&path, "12\t2\t1206\n").unwrap();
+        assert_eq!(
+            Ledger::load(&path)
+                .unwrap()
+                .get(DEFAULT_YEAR, 12, Part::Two),
+            Some(&Expected::Plain("1206".to_string()))
+        );
+
+        fs::write(&path, "7\tone\t3749\n").unwrap();
+        assert!(Ledger::load(&path)
+            .unwrap_err()
+            .ends_with(":1: expected `year day part answer`"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,1012 @@
+// Advent of Code - runner for every day of the year
+
+mod bench;
+mod client;
+mod config;
+mod days;
+mod history;
+mod ledger;
+mod output;
+mod parallel;
+mod profiles;
+mod readme;
+mod scaffold;
+mod submit;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+use aoc::alloc::{Counting, Usage};
+use aoc::input::Source;
+use aoc::solution::{AocError, Day, Part, Run, SolveError, DEFAULT_IMPLEMENTATION, DEFAULT_YEAR};
+
+use crate::bench::Report;
+use crate::config::Config;
+use crate::history::{Change, Environment, Record, Stage};
+use crate::ledger::{Expected, Ledger};
+use crate::output::{Format, Outcome, Status};
+use crate::submit::{Submission, Verdict};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Which days to run and where their inputs come from
+#[derive(Args)]
+struct Selection {
+    /// year of the puzzles
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u32,
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+    /// only run this part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    #[arg(short, long)]
+    all: bool,
+    /// read the input from this file instead, `-` for stdin
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<String>,
+    /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+    #[arg(long, conflicts_with = "input")]
+    inputs: Option<PathBuf>,
+    /// panic on arithmetic overflow in `aoc::checked` values
+    #[arg(long)]
+    checked: bool,
+    /// solve the parts that have an implementation of this name with it instead of the default one
+    #[arg(long = "impl", value_name = "NAME")]
+    implementation: Option<String>,
+    /// also print the allocations and the peak memory of parsing and of every part
+    #[arg(long)]
+    memory: bool,
+    /// run up to N days at the same time, 0 for one per CPU
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+}
+
+impl Selection {
+    /// the selected part, or both
+    fn parts(&self) -> Vec<Part> {
+        match self.part.and_then(Part::from_number) {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// the implementation and parts that the history records a benchmark of this selection as
+    fn variant(&self) -> (String, Vec<Part>) {
+        let implementation = self
+            .implementation
+            .as_deref()
+            .unwrap_or(DEFAULT_IMPLEMENTATION);
+        (implementation.to_string(), self.parts())
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day with `--all`
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// measure N runs after warming up, and print a timing table
+        #[arg(long, value_name = "N", value_parser = at_least_one())]
+        bench: Option<usize>,
+        /// benchmark history file [default: .aoc/bench-history.tsv]
+        #[arg(long)]
+        history: Option<PathBuf>,
+        /// print human readable text, or one JSON or CSV record per day and part
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Benchmark and record the timings, optionally comparing them with earlier ones
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// number of measured runs per day
+        #[arg(long, default_value_t = 10, value_parser = at_least_one())]
+        runs: usize,
+        /// flag every day and stage that got slower than the last recorded run on this machine
+        #[arg(long)]
+        compare: bool,
+        /// slowdown in percent above which a change counts as a regression
+        #[arg(long, default_value_t = 10.0, requires = "compare")]
+        threshold: f64,
+        /// compare with the runs recorded for this commit instead of the latest ones
+        #[arg(long, requires = "compare")]
+        baseline: Option<String>,
+        /// benchmark history file [default: .aoc/bench-history.tsv]
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Download the input of a day into the inputs directory, unless it is already there
+    Fetch {
+        /// year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        #[arg(short, long)]
+        day: u32,
+        /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// website to download from [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit the answer of one part, unless it is known to be wrong
+    Submit {
+        /// year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        #[arg(short, long)]
+        day: u32,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// read the input from this file instead, `-` for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+        #[arg(long, conflicts_with = "input")]
+        inputs: Option<PathBuf>,
+        /// website to submit to [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+        /// log of submitted answers [default: .aoc/submissions.tsv]
+        #[arg(long)]
+        log: Option<PathBuf>,
+        /// accepted answers, a correct answer is added [default: answers.tsv]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Check every solved day against the accepted answers, using the real inputs
+    Verify {
+        /// year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        /// only verify this day
+        #[arg(short, long)]
+        day: Option<u32>,
+        /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// accepted answers [default: answers.tsv]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// add the current answers of parts that have no accepted answer yet
+        #[arg(long)]
+        record: bool,
+        /// record hashes instead of plain answers
+        #[arg(long, requires = "record")]
+        hash: bool,
+        /// panic on arithmetic overflow in `aoc::checked` values
+        #[arg(long)]
+        checked: bool,
+    },
+    /// Run days against every input profile, and report the profiles whose answers differ from
+    /// the expected ones
+    Profiles {
+        /// year of the puzzles
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        /// only run this day
+        #[arg(short, long)]
+        day: Option<u32>,
+        /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// panic on arithmetic overflow in `aoc::checked` values
+        #[arg(long)]
+        checked: bool,
+    },
+    /// Check that every implementation of a part gives the same answers, on the examples, the
+    /// input and the profiles of the day
+    Diff {
+        /// year of the puzzles
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        /// only check this day
+        #[arg(short, long)]
+        day: Option<u32>,
+        /// directory with the inputs [default: $AOC_INPUT_DIR or inputs]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// panic on arithmetic overflow in `aoc::checked` values
+        #[arg(long)]
+        checked: bool,
+    },
+    /// Create the crate of a new day from a template and register it with the runner
+    New {
+        /// year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        #[arg(short, long)]
+        day: u32,
+        /// puzzle title, such as "Warehouse Woes"
+        #[arg(short, long)]
+        title: String,
+        /// workspace to create the day in [default: the workspace of this runner]
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+    /// Regenerate the progress table of the README from the answers and benchmark history
+    Readme {
+        /// year of the puzzle
+        #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+        year: u32,
+        #[arg(long, default_value = "README.md")]
+        readme: PathBuf,
+        /// accepted answers [default: answers.tsv]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// take the runtimes from the benchmarks of this machine instead of keeping those in the table
+        #[arg(long, value_name = "NAME")]
+        machine: Option<String>,
+        /// benchmark history file [default: .aoc/bench-history.tsv]
+        #[arg(long, requires = "machine")]
+        history: Option<PathBuf>,
+        /// link every title to the crate of its day
+        #[arg(long)]
+        links: bool,
+        /// only check that the table is up to date
+        #[arg(long)]
+        check: bool,
+    },
+    /// List the available days
+    List,
+}
+
+/// number of runs, rejecting 0 as there would be nothing to report
+fn at_least_one() -> clap::builder::RangedU64ValueParser<usize> {
+    clap::builder::RangedU64ValueParser::new().range(1..)
+}
+
+/// Why `run_day` gave no run
+enum DayError {
+    /// the day could not run at all, e.g. without an input
+    Setup(String),
+    /// the day failed on its input
+    Failed(AocError),
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    implementation: Option<&str>,
+    source: &Source,
+    runs: usize,
+) -> Result<(Run, Report), DayError> {
+    let read = || {
+        source
+            .read(day.year, day.day)
+            .map_err(|e| DayError::Setup(e.to_string()))
+    };
+    let Some(name) = implementation else {
+        let input = read()?;
+        return bench::bench(day, runs, || (day.run)(&input, parts)).map_err(DayError::Failed);
+    };
+    let input = read()?;
+    let parts = day.select(parts, name);
+    bench::bench(day, runs, || {
+        (day.run_with)(&input, &parts)
+            .ok_or_else(|| {
+                DayError::Setup(format!("day {} has no implementation `{name}`", day.day))
+            })?
+            .map_err(DayError::Failed)
+    })
+}
+
+/// print the answers and timings of `run`, with its allocations when `memory` is set
+fn print_run(day: &Day, run: &Run, memory: bool) {
+    let usage = |usage: Usage| {
+        if !memory {
+            return String::new();
+        }
+        format!(
+            ", {} allocations of {}, peak {}",
+            usage.allocations,
+            bench::format_bytes(usage.bytes),
+            bench::format_bytes(usage.peak)
+        )
+    };
+    println!(
+        "Day {}: {} (parsed in {}{})",
+        day.day,
+        day.title,
+        bench::format_duration(run.parse),
+        usage(run.parse_memory)
+    );
+    for (part, answer, time) in &run.parts {
+        println!(
+            "  Part {part}: {answer} ({}{})",
+            bench::format_duration(*time),
+            usage(run.memory(*part))
+        );
+    }
+}
+
+/// Run the selected days `runs` times each, printing the results in `format`. A day that fails
+/// is reported and skipped, so that `--all` still runs the other days; the error is returned
+/// with the reports of the others.
+fn run_selection(
+    selection: Selection,
+    runs: usize,
+    format: Format,
+) -> Result<(Vec<Report>, Result<(), String>), String> {
+    if selection.checked {
+        aoc::checked::set_enabled(true);
+    }
+    let parts = selection.parts();
+    let selected = match selection.day {
+        Some(day) if !selection.all => vec![days::get(selection.year, day)?],
+        _ => days::of_year(selection.year),
+    };
+    if selected.is_empty() {
+        return Err(format!("no day of {} is solved yet", selection.year));
+    }
+    if let Some(name) = selection.implementation.as_deref() {
+        let names = |day: &Day| {
+            let mut names = parts
+                .iter()
+                .flat_map(|&part| day.implementations(part))
+                .collect::<Vec<_>>();
+            names.dedup();
+            names
+        };
+        if !selected.iter().any(|day| names(day).contains(&name)) {
+            return Err(match selected.as_slice() {
+                [day] => format!(
+                    "day {} has no implementation `{name}`, only {}",
+                    day.day,
+                    names(day).join(", ")
+                ),
+                _ => format!(
+                    "no day of {} has an implementation `{name}`",
+                    selection.year
+                ),
+            });
+        }
+    }
+    let source = match (selection.input, selection.inputs) {
+        (Some(path), _) => Source::from_arg(&path),
+        (None, Some(dir)) => Source::Dir(dir),
+        (None, None) => Source::default_dir(),
+    };
+    let single = selected.len() == 1;
+    let mut errors = Vec::new();
+    let mut reports = Vec::new();
+    let mut outcomes = Vec::new();
+    let jobs = match selection.jobs {
+        0 => parallel::default_jobs(),
+        jobs => jobs,
+    };
+    parallel::map_ordered(
+        &selected,
+        jobs,
+        |day| {
+            let implementation = selection.implementation.as_deref();
+            run_day(day, &parts, implementation, &source, runs)
+        },
+        |day, result| {
+            let (status, e) = match result {
+                Ok((run, report)) => {
+                    match format {
+                        Format::Text => print_run(day, &run, selection.memory),
+                        _ => outcomes.extend(Outcome::solved(&run, &report)),
+                    }
+                    reports.push(report);
+                    return;
+                }
+                Err(DayError::Failed(
+                    e @ AocError {
+                        error: SolveError::Unsolved,
+                        ..
+                    },
+                )) => (Status::Unsolved, e.to_string()),
+                Err(DayError::Failed(e)) => (Status::Error, e.to_string()),
+                Err(DayError::Setup(e)) => (Status::Error, e),
+            };
+            if !single {
+                eprintln!("error: {e}");
+            }
+            outcomes.extend(Outcome::failed(day.year, day.day, &parts, status, &e));
+            errors.push(e);
+        },
+    );
+    match format {
+        Format::Text => {}
+        Format::Json => print!("{}", output::to_json(&outcomes)),
+        Format::Csv => print!("{}", output::to_csv(&outcomes)),
+    }
+    let result = match errors.len() {
+        0 => Ok(()),
+        1 if single => Err(errors.remove(0)),
+        1 => Err("1 day failed".to_string()),
+        n => Err(format!("{n} days failed")),
+    };
+    Ok((reports, result))
+}
+
+/// Append the timings to the history as runs of the implementation and parts of `variant`,
+/// printing them as a table first with `print_table`
+fn record(
+    reports: &[Report],
+    runs: usize,
+    variant: &(String, Vec<Part>),
+    path: &Path,
+    print_table: bool,
+) -> Result<Vec<Record>, String> {
+    if print_table {
+        println!();
+        bench::print_table(reports);
+    }
+    let environment = Environment::current();
+    let (implementation, parts) = variant;
+    let records = reports
+        .iter()
+        .flat_map(|report| Record::from_report(&environment, report, runs, implementation, parts))
+        .collect::<Vec<_>>();
+    history::append(path, &records)?;
+    Ok(records)
+}
+
+/// Benchmarks of days running next to each other compete for the CPU and would be recorded next to
+/// sequential ones, so they run one day at a time.
+fn sequential(selection: &Selection) -> Result<(), String> {
+    match selection.jobs {
+        1 => Ok(()),
+        jobs => Err(format!(
+            "benchmarks run one day at a time, not with --jobs {jobs}"
+        )),
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            selection,
+            bench,
+            history,
+            format,
+        } => {
+            if bench.is_some() {
+                sequential(&selection)?;
+            }
+            let variant = selection.variant();
+            let (reports, result) = run_selection(selection, bench.unwrap_or(1), format)?;
+            let text = format == Format::Text;
+            if let Some(runs) = bench.filter(|_| !reports.is_empty()) {
+                record(&reports, runs, &variant, &history::path(history), text)?;
+            } else if text && reports.len() > 1 {
+                println!();
+                bench::print_table(&reports);
+            }
+            result
+        }
+        Command::Bench {
+            selection,
+            runs,
+            compare,
+            threshold,
+            baseline,
+            history,
+        } => {
+            sequential(&selection)?;
+            let path = history::path(history);
+            // read the history first, so that this run is not compared with itself
+            let previous = history::load(&path)?;
+            let variant = selection.variant();
+            let (reports, result) = run_selection(selection, runs, Format::Text)?;
+            let records = record(&reports, runs, &variant, &path, true)?;
+            if compare {
+                let machine = &Environment::current().machine;
+                let baseline = history::baseline(&previous, machine, baseline.as_deref());
+                let changes = history::compare(&baseline, &records);
+                if changes.is_empty() {
+                    println!("\nNo earlier benchmarks on {machine} to compare with");
+                    return result;
+                }
+                println!();
+                let regressions = print_changes(&changes, threshold);
+                if regressions > 0 {
+                    return Err(format!(
+                        "{regressions} timings got more than {threshold}% slower"
+                    ));
+                }
+            }
+            result
+        }
+        Command::Fetch {
+            year,
+            day,
+            inputs,
+            base_url,
+        } => {
+            let mut config = Config::load()?;
+            if let Some(url) = base_url {
+                config.base_url = url.trim_end_matches('/').to_string();
+            }
+            let dir = inputs.unwrap_or_else(aoc::input::default_dir);
+            match client::fetch_input(&config, &dir, year, day)? {
+                (path, true) => println!("Day {day}: saved input to {}", path.display()),
+                (path, false) => println!("Day {day}: input already in {}", path.display()),
+            }
+            Ok(())
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            input,
+            inputs,
+            base_url,
+            log,
+            answers,
+        } => {
+            let part = Part::from_number(part).expect("part is 1 or 2");
+            let solution = days::get(year, day)?;
+            let source = match (input, inputs) {
+                (Some(path), _) => Source::from_arg(&path),
+                (None, Some(dir)) => Source::Dir(dir),
+                (None, None) => Source::default_dir(),
+            };
+            let input = source.read(year, day).map_err(|e| e.to_string())?;
+            let answer = (solution.solve)(&input, part).map_err(|e| match e.error {
+                SolveError::Unsolved => format!("not submitting, part {part} of {e}"),
+                SolveError::Parse(_) => e.to_string(),
+            })?;
+            println!("Day {day} part {part}: {answer}");
+
+            let log = log.unwrap_or_else(|| PathBuf::from(submit::DEFAULT_LOG));
+            let submissions = submit::load(&log)?;
+            submit::check(&submissions, year, day, part, &answer, submit::now())
+                .map_err(|e| format!("not submitting, {e}"))?;
+
+            let mut config = Config::load()?;
+            if let Some(url) = base_url {
+                config.base_url = url.trim_end_matches('/').to_string();
+            }
+            let (verdict, wait) = client::Client::new(&config)?.submit(year, day, part, &answer)?;
+            let submission = Submission::new(year, day, part, &answer, verdict, wait);
+            submit::append(&log, &submission)?;
+            println!("The answer is {verdict}");
+            if verdict == Verdict::Correct {
+                let path = answers.unwrap_or_else(|| PathBuf::from(ledger::DEFAULT_PATH));
+                let mut ledger = Ledger::load(&path)?;
+                let hash = config.hash_answers || ledger.is_hashed();
+                ledger.insert(
+                    year,
+                    day,
+                    part,
+                    Expected::new(year, day, part, &answer, hash),
+                );
+                ledger.save(&path)?;
+            }
+            if verdict.is_wrong() {
+                return Err(format!(
+                    "wrong answer, wait {}s before the next one",
+                    wait.as_secs()
+                ));
+            }
+            if verdict == Verdict::Wait {
+                return Err(format!("wait {}s before submitting again", wait.as_secs()));
+            }
+            Ok(())
+        }
+        Command::Verify {
+            year,
+            day,
+            inputs,
+            answers,
+            record,
+            hash,
+            checked,
+        } => {
+            if checked {
+                aoc::checked::set_enabled(true);
+            }
+            let path = answers.unwrap_or_else(|| PathBuf::from(ledger::DEFAULT_PATH));
+            let mut ledger = Ledger::load(&path)?;
+            let hash = hash || Config::load()?.hash_answers || ledger.is_hashed();
+            let source = inputs.map_or_else(Source::default_dir, Source::Dir);
+            let selected = match day {
+                Some(day) => vec![days::get(year, day)?],
+                None => days::of_year(year),
+            };
+            let summary = verify(&selected, &mut ledger, &source, record.then_some(hash));
+            if record {
+                ledger.save(&path)?;
+            }
+            summary.print();
+            summary.result()
+        }
+        Command::Profiles {
+            year,
+            day,
+            inputs,
+            checked,
+        } => {
+            if checked {
+                aoc::checked::set_enabled(true);
+            }
+            let inputs = inputs.unwrap_or_else(aoc::input::default_dir);
+            let selected = match day {
+                Some(day) => vec![days::get(year, day)?],
+                None => days::of_year(year),
+            };
+            run_profiles(&selected, &inputs)
+        }
+        Command::Diff {
+            year,
+            day,
+            inputs,
+            checked,
+        } => {
+            if checked {
+                aoc::checked::set_enabled(true);
+            }
+            let inputs = inputs.unwrap_or_else(aoc::input::default_dir);
+            let selected = match day {
+                Some(day) => vec![days::get(year, day)?],
+                None => days::of_year(year),
+            };
+            diff(&selected, &inputs)
+        }
+        Command::New {
+            year,
+            day,
+            title,
+            root,
+        } => {
+            let root = root.unwrap_or_else(scaffold::workspace_root);
+            for path in scaffold::new_day(&root, year, day, &title)? {
+                println!("wrote {}", path.display());
+            }
+            Ok(())
+        }
+        Command::Readme {
+            year,
+            readme,
+            answers,
+            machine,
+            history,
+            links,
+            check,
+        } => {
+            let answers = answers.unwrap_or_else(|| PathBuf::from(ledger::DEFAULT_PATH));
+            if !answers.exists() {
+                return Err(format!(
+                    "no answers at {}, which the stars come from; record them with `aoc verify --record`",
+                    answers.display()
+                ));
+            }
+            let ledger = Ledger::load(&answers)?;
+            let content = fs::read_to_string(&readme)
+                .map_err(|e| format!("cannot read {}: {e}", readme.display()))?;
+            let mut medians = readme::medians(&content);
+            if let Some(machine) = machine {
+                let records = history::load(&history::path(history))?;
+                if !records.iter().any(|r| r.environment.machine == machine) {
+                    return Err(format!(
+                        "no benchmarks of machine `{machine}` in the history"
+                    ));
+                }
+                medians = history::baseline(&records, &machine, None)
+                    .into_iter()
+                    .filter(|((y, _, stage, implementation, parts), _)| {
+                        (*y, *stage, *implementation, *parts)
+                            == (year, Stage::Total, DEFAULT_IMPLEMENTATION, &Part::ALL[..])
+                    })
+                    .map(|((_, day, ..), record)| {
+                        (day, bench::format_duration(record.stats.median))
+                    })
+                    .collect();
+            }
+            let rows = days::of_year(year)
+                .into_iter()
+                .map(|day| {
+                    let row = readme::Row {
+                        title: Some(day.title),
+                        median: medians.remove(&day.day),
+                    };
+                    (day.day, row)
+                })
+                .collect();
+            let table = readme::table(&ledger, year, &rows, links);
+            let updated = readme::replace_section(&content, &table)?;
+            if updated == content {
+                println!("{} is up to date", readme.display());
+            } else if check {
+                return Err(format!(
+                    "the progress table in {} is out of date",
+                    readme.display()
+                ));
+            } else {
+                fs::write(&readme, updated)
+                    .map_err(|e| format!("cannot write {}: {e}", readme.display()))?;
+                println!("updated {}", readme.display());
+            }
+            Ok(())
+        }
+        Command::List => {
+            for day in days::DAYS {
+                println!("{}  {:>2}  {}", day.year, day.day, day.title);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[derive(Default)]
+struct Verification {
+    verified: usize,
+    recorded: usize,
+    mismatches: usize,
+    failed_days: usize,
+    unknown: usize,
+}
+
+impl Verification {
+    fn print(&self) {
+        println!(
+            "\n{} verified, {} recorded, {} mismatched, {} without an accepted answer",
+            self.verified, self.recorded, self.mismatches, self.unknown
+        );
+    }
+
+    fn result(&self) -> Result<(), String> {
+        match (self.mismatches, self.failed_days) {
+            (0, 0) => Ok(()),
+            (0, 1) => Err("1 day could not be verified".to_string()),
+            (0, n) => Err(format!("{n} days could not be verified")),
+            (1, _) => Err("1 answer does not match the accepted one".to_string()),
+            (n, _) => Err(format!("{n} answers do not match the accepted ones")),
+        }
+    }
+}
+
+/// Compare the answers of `selected` days with the ledger. With `record` set, answers that are
+/// not in the ledger yet are added, hashed if `record` is `Some(true)`.
+fn verify(
+    selected: &[&Day],
+    ledger: &mut Ledger,
+    source: &Source,
+    record: Option<bool>,
+) -> Verification {
+    let mut summary = Verification::default();
+    for day in selected {
+        let known = Part::ALL.map(|part| ledger.get(day.year, day.day, part).is_some());
+        if record.is_none() && !known.contains(&true) {
+            summary.unknown += 2;
+            continue;
+        }
+        let input = match source.read(day.year, day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                summary.failed_days += 1;
+                continue;
+            }
+        };
+        for part in Part::ALL {
+            let answer = match (day.solve)(&input, part) {
+                Ok(answer) => answer,
+                Err(AocError {
+                    error: SolveError::Unsolved,
+                    ..
+                }) => {
+                    println!("Day {} part {part}: not implemented yet", day.day);
+                    summary.unknown += 1;
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    summary.failed_days += 1;
+                    break;
+                }
+            };
+            match (ledger.get(day.year, day.day, part), record) {
+                (Some(expected), _) if expected.matches(day.year, day.day, part, &answer) => {
+                    println!("Day {} part {part}: ok", day.day);
+                    summary.verified += 1;
+                }
+                (Some(expected), _) => {
+                    println!(
+                        "Day {} part {part}: MISMATCH, expected {expected} but got {answer}",
+                        day.day
+                    );
+                    summary.mismatches += 1;
+                }
+                (None, Some(hash)) => {
+                    println!("Day {} part {part}: recorded {answer}", day.day);
+                    let expected = Expected::new(day.year, day.day, part, &answer, hash);
+                    ledger.insert(day.year, day.day, part, expected);
+                    summary.recorded += 1;
+                }
+                (None, None) => summary.unknown += 1,
+            }
+        }
+    }
+    summary
+}
+
+/// Run every day against each of its profiles, failing when any answer differs from the expected
+/// one. Answers without an expected one are printed.
+fn run_profiles(selected: &[&Day], inputs: &Path) -> Result<(), String> {
+    let (mut profiles_run, mut disagreeing) = (0, 0);
+    for day in selected {
+        let dir = profiles::dir_in(inputs, day.year, day.day);
+        let profiles = profiles::load(&dir)?;
+        if profiles.is_empty() {
+            // only mention missing profiles when a single day was asked for
+            if selected.len() == 1 {
+                println!("Day {}: no profiles in {}", day.day, dir.display());
+            }
+            continue;
+        }
+        println!("Day {}: {}", day.day, day.title);
+        for profile in profiles {
+            let input = fs::read_to_string(&profile.path)
+                .map_err(|e| format!("cannot read {}: {e}", profile.path.display()))?;
+            profiles_run += 1;
+            let run = match (day.run)(&input, &Part::ALL) {
+                Ok(run) => run,
+                Err(e) => {
+                    println!("  {}: error: {e}", profile.name);
+                    disagreeing += 1;
+                    continue;
+                }
+            };
+            let mismatches = profile.mismatches(&run);
+            let agrees = mismatches.is_empty();
+            if !agrees {
+                disagreeing += 1;
+                for (part, expected, answer) in mismatches {
+                    println!(
+                        "  {}: part {part} MISMATCH, expected {expected} but got {answer}",
+                        profile.name
+                    );
+                }
+            }
+            let unknown = run
+                .parts
+                .iter()
+                .filter(|(part, _, _)| !profile.expected.contains_key(part))
+                .map(|(part, answer, _)| format!("part {part}: {answer}"))
+                .collect::<Vec<_>>();
+            if !unknown.is_empty() {
+                println!(
+                    "  {}: {} (no expected answer)",
+                    profile.name,
+                    unknown.join(", ")
+                );
+            } else if agrees {
+                println!("  {}: ok", profile.name);
+            }
+        }
+    }
+    println!("\n{profiles_run} profiles run, {disagreeing} disagreeing");
+    match disagreeing {
+        0 => Ok(()),
+        1 => Err("1 profile disagrees with its expected answers".to_string()),
+        n => Err(format!("{n} profiles disagree with their expected answers")),
+    }
+}
+
+/// Solve the examples, the input and the profiles of every day with each implementation of the
+/// parts that have several, failing when any of them disagree.
+fn diff(selected: &[&Day], inputs: &Path) -> Result<(), String> {
+    let mut disagreements = 0;
+    let mut compared = 0;
+    for day in selected {
+        let parts = Part::ALL
+            .into_iter()
+            .map(|part| (part, day.implementations(part)))
+            .filter(|(_, names)| names.len() > 1)
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            if selected.len() == 1 {
+                println!("Day {}: a single implementation of each part", day.day);
+            }
+            continue;
+        }
+        println!("Day {}: {}", day.day, day.title);
+        let mut sources = day
+            .examples
+            .iter()
+            .enumerate()
+            .map(|(i, example)| (format!("example {}", i + 1), example.to_string()))
+            .collect::<Vec<_>>();
+        let path = aoc::input::path_in(inputs, day.year, day.day);
+        match fs::read_to_string(&path) {
+            Ok(input) => sources.push(("input".to_string(), input)),
+            Err(_) => println!("  no input at {}", path.display()),
+        }
+        for profile in profiles::load(&profiles::dir_in(inputs, day.year, day.day))? {
+            let input = fs::read_to_string(&profile.path)
+                .map_err(|e| format!("cannot read {}: {e}", profile.path.display()))?;
+            sources.push((format!("profile {}", profile.name), input));
+        }
+        for (source, input) in &sources {
+            for (part, names) in &parts {
+                let answers = names
+                    .iter()
+                    .map(|&name| {
+                        // implementations that fail on the same input agree on the error
+                        let answer = match (day.run_with)(input, &[(*part, name)]) {
+                            Some(Ok(run)) => run.parts[0].1.to_string(),
+                            Some(Err(e)) => format!("error ({e})"),
+                            None => format!("error (no implementation `{name}`)"),
+                        };
+                        (name, answer)
+                    })
+                    .collect::<Vec<_>>();
+                compared += 1;
+                if answers.iter().all(|(_, answer)| *answer == answers[0].1) {
+                    println!(
+                        "  {source} part {part}: {} ({})",
+                        answers[0].1,
+                        names.join(", ")
+                    );
+                } else {
+                    disagreements += 1;
+                    let answers = answers
+                        .iter()
+                        .map(|(name, answer)| format!("{name} {answer}"))
+                        .collect::<Vec<_>>();
+                    println!("  {source} part {part}: DISAGREE, {}", answers.join(", "));
+                }
+            }
+        }
+    }
+    println!("\n{compared} compared, {disagreements} disagreeing");
+    match disagreements {
+        0 => Ok(()),
+        1 => Err("the implementations disagree on 1 answer".to_string()),
+        n => Err(format!("the implementations disagree on {n} answers")),
+    }
+}
+
+/// print the changes in median time, returning the number of regressions
+fn print_changes(changes: &[Change], threshold: f64) -> usize {
+    println!(
+        "{:>4}  {:<6}  {:>9}  {:>9}  {:>8}",
+        "Day", "Stage", "Before", "After", "Change"
+    );
+    let mut regressions = 0;
+    for change in changes {
+        let flag = if change.is_regression(threshold) {
+            regressions += 1;
+            "  slower"
+        } else {
+            ""
+        };
+        println!(
+            "{:>4}  {:<6}  {:>9}  {:>9}  {:>+7.1}%{flag}",
+            change.day,
+            change.stage,
+            bench::format_duration(change.before),
+            bench::format_duration(change.after),
+            change.percent(),
+        );
+    }
+    regressions
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
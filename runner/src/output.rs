@@ -0,0 +1,264 @@
+//! Machine readable results of a run, one record per day and part.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use aoc::alloc::{self, Usage};
+use aoc::solution::{Part, Run};
+
+use crate::bench::Report;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// How a part ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Error,
+    /// the part is not implemented yet
+    Unsolved,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Unsolved => "unsolved",
+        }
+    }
+}
+
+/// Result of one part of one day. Times are medians over the measured runs, allocations are those
+/// of the last run and missing when the counting allocator is not installed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub parse_time: Option<Duration>,
+    pub solve_time: Option<Duration>,
+    pub parse_memory: Option<Usage>,
+    pub solve_memory: Option<Usage>,
+    pub status: Status,
+    /// `None` when the part was solved
+    pub error: Option<String>,
+}
+
+impl Outcome {
+    pub fn solved(run: &Run, report: &Report) -> Vec<Outcome> {
+        run.parts
+            .iter()
+            .map(|(part, answer, _)| Outcome {
+                year: report.year,
+                day: report.day,
+                part: *part,
+                answer: Some(answer.to_string()),
+                parse_time: Some(report.parse.median),
+                solve_time: Some(match part {
+                    Part::One => report.part_one.median,
+                    Part::Two => report.part_two.median,
+                }),
+                parse_memory: alloc::is_installed().then_some(run.parse_memory),
+                solve_memory: alloc::is_installed().then_some(run.memory(*part)),
+                status: Status::Ok,
+                error: None,
+            })
+            .collect()
+    }
+
+    pub fn failed(
+        year: u32,
+        day: u32,
+        parts: &[Part],
+        status: Status,
+        error: &str,
+    ) -> Vec<Outcome> {
+        parts
+            .iter()
+            .map(|&part| Outcome {
+                year,
+                day,
+                part,
+                answer: None,
+                parse_time: None,
+                solve_time: None,
+                parse_memory: None,
+                solve_memory: None,
+                status,
+                error: Some(error.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// JSON array with one object per outcome, times in nanoseconds
+pub fn to_json(outcomes: &[Outcome]) -> String {
+    let string = |s: &Option<String>| s.as_deref().map_or("null".to_string(), json_string);
+    let number = |n: Option<u128>| n.map_or("null".to_string(), |n| n.to_string());
+    let objects = outcomes
+        .iter()
+        .map(|o| {
+            let [parse_allocations, parse_bytes, parse_peak] = memory(o.parse_memory).map(number);
+            let [solve_allocations, solve_bytes, solve_peak] = memory(o.solve_memory).map(number);
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \
+                 \"parse_allocations\": {parse_allocations}, \"parse_allocated_bytes\": {parse_bytes}, \
+                 \"parse_peak_bytes\": {parse_peak}, \"solve_allocations\": {solve_allocations}, \
+                 \"solve_allocated_bytes\": {solve_bytes}, \"solve_peak_bytes\": {solve_peak}, \
+                 \"status\": \"{}\", \"error\": {}}}",
+                o.year,
+                o.day,
+                o.part.number(),
+                string(&o.answer),
+                number(o.parse_time.map(|t| t.as_nanos())),
+                number(o.solve_time.map(|t| t.as_nanos())),
+                o.status.name(),
+                string(&o.error),
+            )
+        })
+        .collect::<Vec<_>>();
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+/// allocations, allocated bytes and peak bytes, each missing when not measured
+fn memory(usage: Option<Usage>) -> [Option<u128>; 3] {
+    [
+        usage.map(|u| u.allocations.into()),
+        usage.map(|u| u.bytes.into()),
+        usage.map(|u| u.peak.into()),
+    ]
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// CSV with a header line, times in nanoseconds and empty fields for missing values
+pub fn to_csv(outcomes: &[Outcome]) -> String {
+    let mut csv = String::from(
+        "year,day,part,answer,parse_ns,solve_ns,parse_allocations,parse_allocated_bytes,\
+         parse_peak_bytes,solve_allocations,solve_allocated_bytes,solve_peak_bytes,status,error\n",
+    );
+    let string = |s: &Option<String>| s.as_deref().map_or(String::new(), csv_field);
+    let number = |n: Option<u128>| n.map_or(String::new(), |n| n.to_string());
+    for o in outcomes {
+        let parse_memory = memory(o.parse_memory).map(number);
+        let solve_memory = memory(o.solve_memory).map(number);
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{},{}",
+            o.year,
+            o.day,
+            o.part.number(),
+            string(&o.answer),
+            number(o.parse_time.map(|t| t.as_nanos())),
+            number(o.solve_time.map(|t| t.as_nanos())),
+            parse_memory.join(","),
+            solve_memory.join(","),
+            o.status.name(),
+            string(&o.error),
+        )
+        .unwrap();
+    }
+    csv
+}
+
+/// quoted when it contains a separator, quote or line break
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes() -> Vec<Outcome> {
+        let mut outcomes = vec![Outcome {
+            year: 2024,
+            day: 7,
+            part: Part::One,
+            answer: Some("3749".to_string()),
+            parse_time: Some(Duration::from_nanos(120)),
+            solve_time: Some(Duration::from_micros(15)),
+            parse_memory: Some(Usage {
+                allocations: 3,
+                bytes: 4096,
+                peak: 2048,
+            }),
+            solve_memory: Some(Usage::default()),
+            status: Status::Ok,
+            error: None,
+        }];
+        outcomes.extend(Outcome::failed(
+            2024,
+            9,
+            &[Part::Two],
+            Status::Error,
+            "no input for day 9 at \"inputs/day-9.txt\", save it there",
+        ));
+        outcomes.extend(Outcome::failed(
+            2024,
+            15,
+            &[Part::One],
+            Status::Unsolved,
+            "day 15: not implemented yet",
+        ));
+        outcomes
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_json(&outcomes()),
+            r#"[
+  {"year": 2024, "day": 7, "part": 1, "answer": "3749", "parse_ns": 120, "solve_ns": 15000, "parse_allocations": 3, "parse_allocated_bytes": 4096, "parse_peak_bytes": 2048, "solve_allocations": 0, "solve_allocated_bytes": 0, "solve_peak_bytes": 0, "status": "ok", "error": null},
+  {"year": 2024, "day": 9, "part": 2, "answer": null, "parse_ns": null, "solve_ns": null, "parse_allocations": null, "parse_allocated_bytes": null, "parse_peak_bytes": null, "solve_allocations": null, "solve_allocated_bytes": null, "solve_peak_bytes": null, "status": "error", "error": "no input for day 9 at \"inputs/day-9.txt\", save it there"},
+  {"year": 2024, "day": 15, "part": 1, "answer": null, "parse_ns": null, "solve_ns": null, "parse_allocations": null, "parse_allocated_bytes": null, "parse_peak_bytes": null, "solve_allocations": null, "solve_allocated_bytes": null, "solve_peak_bytes": null, "status": "unsolved", "error": "day 15: not implemented yet"}
+]
+"#
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+        assert_eq!(json_string("a\u{1}b"), "\"a\\u0001b\"");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            to_csv(&outcomes()),
+            "year,day,part,answer,parse_ns,solve_ns,parse_allocations,parse_allocated_bytes,\
+             parse_peak_bytes,solve_allocations,solve_allocated_bytes,solve_peak_bytes,status,error\n\
+             2024,7,1,3749,120,15000,3,4096,2048,0,0,0,ok,\n\
+             2024,9,2,,,,,,,,,,error,\"no input for day 9 at \"\"inputs/day-9.txt\"\", save it there\"\n\
+             2024,15,1,,,,,,,,,,unsolved,day 15: not implemented yet\n"
+        );
+    }
+}
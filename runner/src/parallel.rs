@@ -0,0 +1,85 @@
+//! Running independent days on a small pool of threads, with the results kept in order.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// number of jobs to use when none are given
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Apply `f` to every item on up to `jobs` threads, handing each result to `done` in the order
+/// of the items, as soon as it and every result before it are ready.
+pub fn map_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(&T, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    let jobs = jobs.clamp(1, items.len().max(1));
+    if jobs == 1 {
+        for item in items {
+            done(item, f(item));
+        }
+        return;
+    }
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                if sender.send((i, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+        let mut pending = BTreeMap::new();
+        let mut first = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&first) {
+                done(&items[first], result);
+                first += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_map_ordered() {
+        let items = (0..20_u64).collect::<Vec<_>>();
+        for jobs in [0, 1, 4, 50] {
+            let mut results = Vec::new();
+            map_ordered(
+                &items,
+                jobs,
+                |&n| {
+                    // later items finish first
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * n
+                },
+                |&n, square| results.push((n, square)),
+            );
+            assert_eq!(
+                results,
+                items.iter().map(|&n| (n, n * n)).collect::<Vec<_>>()
+            );
+        }
+    }
+}
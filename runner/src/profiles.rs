@@ -0,0 +1,155 @@
+//! Input profiles: several inputs of the same day, such as those of everyone in a team, each with
+//! its expected answers.
+//!
+//! The profiles of day `N` are the `NAME.txt` files in `inputs/day-N/` (`inputs/YYYY/day-N/` for
+//! other years), next to an `answers.tsv` with one `name part answer` line per known answer.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc::solution::{Answer, Part, Run};
+
+pub const ANSWERS_FILE: &str = "answers.tsv";
+
+/// directory with the profiles of `day` of `year` in the inputs directory `inputs`
+pub fn dir_in(inputs: &Path, year: u32, day: u32) -> PathBuf {
+    aoc::input::path_in(inputs, year, day).with_extension("")
+}
+
+/// One input of a day, with the answers it is known to have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: BTreeMap<Part, String>,
+}
+
+impl Profile {
+    /// every answer of `run` that differs from the expected one, with the expected answer
+    pub fn mismatches<'a>(&'a self, run: &'a Run) -> Vec<(Part, &'a str, &'a Answer)> {
+        run.parts
+            .iter()
+            .filter_map(|(part, answer, _)| {
+                let expected = self.expected.get(part)?;
+                (*expected != answer.to_string()).then_some((*part, expected.as_str(), answer))
+            })
+            .collect()
+    }
+}
+
+/// every profile in `dir`, ordered by name, none if the directory does not exist
+pub fn load(dir: &Path) -> Result<Vec<Profile>, String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("cannot read {}: {e}", dir.display())),
+    };
+    let mut profiles = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("cannot read {}: {e}", dir.display()))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            profiles.push(Profile {
+                name: name.into_owned(),
+                path,
+                expected: BTreeMap::new(),
+            });
+        }
+    }
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let answers = dir.join(ANSWERS_FILE);
+    let content = match fs::read_to_string(&answers) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(profiles),
+        Err(e) => return Err(format!("cannot read {}: {e}", answers.display())),
+    };
+    for (i, line) in content.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = |reason: &str| format!("{}:{}: {reason}", answers.display(), i + 1);
+        let (name, part, answer) =
+            parse_line(line).ok_or_else(|| invalid("expected `name part answer`"))?;
+        let profile = profiles
+            .iter_mut()
+            .find(|profile| profile.name == name)
+            .ok_or_else(|| invalid(&format!("there is no profile {name}")))?;
+        profile.expected.insert(part, answer.to_string());
+    }
+    Ok(profiles)
+}
+
+fn parse_line(line: &str) -> Option<(&str, Part, &str)> {
+    let mut fields = line.splitn(3, '\t');
+    let name = fields.next()?;
+    let part = Part::from_number(fields.next()?.parse().ok()?)?;
+    Some((name, part, fields.next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::alloc::Usage;
+
+    use super::*;
+
+    #[test]
+    fn test_dir_in() {
+        let inputs = Path::new("inputs");
+        assert_eq!(dir_in(inputs, 2024, 14), inputs.join("day-14"));
+        assert_eq!(dir_in(inputs, 2023, 5), inputs.join("2023").join("day-5"));
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["bob", "alice"] {
+            fs::write(dir.join(format!("{name}.txt")), "125 17").unwrap();
+        }
+        fs::write(dir.join("notes.md"), "not a profile").unwrap();
+        fs::write(
+            dir.join(ANSWERS_FILE),
+            "alice\t1\t55312\nalice\t2\t65601038650482\n",
+        )
+        .unwrap();
+
+        let profiles = load(&dir).unwrap();
+        let names = profiles.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(profiles[0].expected[&Part::One], "55312");
+        assert!(profiles[1].expected.is_empty());
+
+        let mut run = Run::new(Duration::ZERO, Usage::default());
+        run.push(
+            Part::One,
+            Answer::Number(55312),
+            Duration::ZERO,
+            Usage::default(),
+        );
+        run.push(
+            Part::Two,
+            Answer::Number(1),
+            Duration::ZERO,
+            Usage::default(),
+        );
+        assert_eq!(
+            profiles[0].mismatches(&run),
+            [(Part::Two, "65601038650482", &Answer::Number(1))]
+        );
+        assert!(profiles[1].mismatches(&run).is_empty());
+
+        fs::write(dir.join(ANSWERS_FILE), "carol\t1\t1\n").unwrap();
+        assert!(load(&dir)
+            .unwrap_err()
+            .ends_with(":1: there is no profile carol"));
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(load(&dir).unwrap().is_empty());
+    }
+}
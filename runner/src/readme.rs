@@ -0,0 +1,150 @@
+//! Progress table of the README, generated from the answers ledger and the benchmark history.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use aoc::solution::Part;
+
+use crate::ledger::Ledger;
+use crate::scaffold::crate_dir;
+
+pub const START: &str = "<!-- progress:start -->";
+pub const END: &str = "<!-- progress:end -->";
+const STAR: &str = "⭐️";
+
+/// What is known about one day
+#[derive(Debug, Clone, Default)]
+pub struct Row<'a> {
+    pub title: Option<&'a str>,
+    /// median runtime as shown in the table, e.g. `152µs`
+    pub median: Option<String>,
+}
+
+/// Markdown table of all 25 days of `year`: a star for every part in the ledger, the title, and
+/// the median runtime. With `links` the titles link to the crates of the days.
+pub fn table(ledger: &Ledger, year: u32, rows: &BTreeMap<u32, Row>, links: bool) -> String {
+    let mut table = String::from("| Day | Stars | Title | Median runtime |\n");
+    table.push_str("|----:|:-----:|-------|---------------:|\n");
+    for day in 1..=25 {
+        let row = rows.get(&day).cloned().unwrap_or_default();
+        let solved = Part::ALL
+            .iter()
+            .filter(|&&part| ledger.get(year, day, part).is_some())
+            .count();
+        let stars = STAR.repeat(solved);
+        let title = match row.title {
+            Some(title) if links => {
+                format!("[{title}]({}/)", crate_dir(year, day).display())
+            }
+            Some(title) => title.to_string(),
+            None => String::new(),
+        };
+        let median = row.median.unwrap_or_default();
+        writeln!(table, "| {day} | {stars} | {title} | {median} |").unwrap();
+    }
+    table
+}
+
+/// median runtime of every day in the progress table of `readme`, where it has one
+pub fn medians(readme: &str) -> BTreeMap<u32, String> {
+    let section = readme
+        .split_once(START)
+        .and_then(|(_, rest)| rest.split_once(END))
+        .map_or("", |(section, _)| section);
+    section
+        .lines()
+        .filter_map(|line| {
+            // the median is the last cell, whatever the title contains
+            let cells = line.split('|').map(str::trim).collect::<Vec<_>>();
+            let &[_, day, .., median, _] = cells.as_slice() else {
+                return None;
+            };
+            Some((day.parse().ok()?, median.to_string()))
+        })
+        .filter(|(_, median)| !median.is_empty())
+        .collect()
+}
+
+/// `readme` with the text between the progress markers replaced by `table`
+pub fn replace_section(readme: &str, table: &str) -> Result<String, String> {
+    let missing = || format!("the README has no `{START}` ... `{END}` section");
+    let start = readme.find(START).ok_or_else(missing)? + START.len();
+    let end = start + readme[start..].find(END).ok_or_else(missing)?;
+    Ok(format!("{}\n{table}{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::solution::Answer;
+
+    use super::*;
+    use crate::ledger::Expected;
+
+    #[test]
+    fn test_table() {
+        let mut ledger = Ledger::default();
+        ledger.insert(2024, 1, Part::One, Expected::Plain("11".to_string()));
+        ledger.insert(2024, 1, Part::Two, Expected::Plain("31".to_string()));
+        ledger.insert(
+            2024,
+            2,
+            Part::One,
+            Expected::new(2024, 2, Part::One, &Answer::Number(2), true),
+        );
+        ledger.insert(2023, 3, Part::One, Expected::Plain("4361".to_string()));
+        let rows = BTreeMap::from([
+            (
+                1,
+                Row {
+                    title: Some("Historian Hysteria"),
+                    median: Some("152µs".to_string()),
+                },
+            ),
+            (
+                2,
+                Row {
+                    title: Some("Red-Nosed Reports"),
+                    median: None,
+                },
+            ),
+        ]);
+
+        let table = table(&ledger, 2024, &rows, false);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 27);
+        assert_eq!(lines[2], "| 1 | ⭐️⭐️ | Historian Hysteria | 152µs |");
+        assert_eq!(lines[3], "| 2 | ⭐️ | Red-Nosed Reports |  |");
+        assert_eq!(lines[4], "| 3 |  |  |  |");
+        assert_eq!(lines[5], "| 4 |  |  |  |");
+        assert_eq!(lines[26], "| 25 |  |  |  |");
+
+        let linked = super::table(&ledger, 2024, &rows, true);
+        assert!(linked.contains("| [Historian Hysteria](day-1/) |"));
+        let linked = super::table(&ledger, 2023, &rows, true);
+        assert!(linked.contains("| [Historian Hysteria](2023/day-1/) |"));
+    }
+
+    #[test]
+    fn test_medians() {
+        let readme = format!(
+            "| 9 | ⭐️ | x | 2s |\n{START}\n| Day | Stars | Title | Median runtime |\n\
+             |----:|:-----:|-------|---------------:|\n| 1 | ⭐️⭐️ | A | 152µs |\n\
+             | 2 | ⭐️ | B |  |\n| 3 |  | [C \\| D](day-3/) | 1.20ms |\n{END}\n"
+        );
+        assert_eq!(
+            medians(&readme),
+            BTreeMap::from([(1, "152µs".to_string()), (3, "1.20ms".to_string())])
+        );
+        assert_eq!(medians("# AoC\n"), BTreeMap::new());
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# AoC\n\n{START}\nold\n{END}\n\n## Running\n");
+        assert_eq!(
+            replace_section(&readme, "new\n").unwrap(),
+            format!("# AoC\n\n{START}\nnew\n{END}\n\n## Running\n")
+        );
+        assert!(replace_section("# AoC\n", "new\n").is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Generator for the crate of a new day, registered with the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::solution::DEFAULT_YEAR;
+
+/// year of the first Advent of Code
+const FIRST_YEAR: u32 = 2015;
+
+/// root of the workspace this runner was built in
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// directory of the crate of `day` of `year`, relative to the workspace root
+pub fn crate_dir(year: u32, day: u32) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::from(format!("day-{day}"))
+    } else {
+        Path::new(&year.to_string()).join(format!("day-{day}"))
+    }
+}
+
+/// package name of the crate of `day` of `year`: `day-N`, or `day-N-YYYY` for other years
+fn package(year: u32, day: u32) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day-{day}")
+    } else {
+        format!("day-{day}-{year}")
+    }
+}
+
+/// Create the crate of `day` of `year` in the workspace at `root` and register it with the
+/// runner, returning the files that were created or changed.
+pub fn new_day(root: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}, days go from 1 to 25"));
+    }
+    // later years are allowed, so that a new year can be started without changing the runner
+    if year < FIRST_YEAR {
+        return Err(format!(
+            "there is no Advent of Code {year}, the first one was in {FIRST_YEAR}"
+        ));
+    }
+    let dir = root.join(crate_dir(year, day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let package = package(year, day);
+    let krate = package.replace('-', "_");
+    let manifest = root.join("runner").join("Cargo.toml");
+    let days = root.join("runner").join("src").join("days.rs");
+    // prepare every change first, so that nothing is written when one of them fails
+    let manifest_content = insert_sorted(
+        &read(&manifest)?,
+        (year, day),
+        &format!(
+            "{package} = {{ path = \"../{}\" }}",
+            crate_dir(year, day).display()
+        ),
+        |line| key(line.trim_start().split_once(" = ")?.0, '-'),
+    )
+    .ok_or_else(|| format!("no day dependencies found in {}", manifest.display()))?;
+    let days_content = insert_sorted(
+        &read(&days)?,
+        (year, day),
+        &format!("    Day::of::<{krate}::Day{day}>(),"),
+        |line| {
+            let name = line.trim_start().strip_prefix("Day::of::<")?;
+            key(&name[..name.find("::")?], '_')
+        },
+    )
+    .ok_or_else(|| format!("no registered days found in {}", days.display()))?;
+
+    let mut files = vec![
+        (dir.join("Cargo.toml"), cargo_toml(year, day)),
+        (dir.join("src").join("lib.rs"), lib_rs(year, day, title)),
+        (dir.join("src").join("main.rs"), main_rs(&krate, day)),
+        (manifest, manifest_content),
+        (days, days_content),
+    ];
+    if year != DEFAULT_YEAR {
+        let workspace = root.join("Cargo.toml");
+        let members = add_member(&read(&workspace)?, &format!("{year}/day-*"))
+            .ok_or_else(|| format!("no workspace members found in {}", workspace.display()))?;
+        files.push((workspace, members));
+    }
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    for (path, content) in &files {
+        fs::write(path, content).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))
+}
+
+/// year and day of a crate named `day-N` or `day-N-YYYY`, with `separator` between the words
+fn key(name: &str, separator: char) -> Option<(u32, u32)> {
+    let mut words = name.split(separator);
+    if words.next()? != "day" {
+        return None;
+    }
+    let day = words.next()?.parse().ok()?;
+    let year = match words.next() {
+        Some(year) => year.parse().ok()?,
+        None => DEFAULT_YEAR,
+    };
+    words.next().is_none().then_some((year, day))
+}
+
+/// Insert `new_line` among the lines with a key, keeping them in order. `None` when no line has
+/// a key.
+fn insert_sorted(
+    content: &str,
+    new_key: (u32, u32),
+    new_line: &str,
+    key: impl Fn(&str) -> Option<(u32, u32)>,
+) -> Option<String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect::<Vec<_>>();
+    let &(first, _) = keyed.first()?;
+    let at = keyed
+        .iter()
+        .filter(|&&(_, k)| k < new_key)
+        .map(|&(i, _)| i + 1)
+        .next_back()
+        .unwrap_or(first);
+    let mut lines = lines;
+    lines.insert(at, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// workspace manifest with `pattern` added to its members, unchanged if it is already there
+fn add_member(manifest: &str, pattern: &str) -> Option<String> {
+    let quoted = format!("\"{pattern}\"");
+    let lines = manifest
+        .lines()
+        .map(|line| match line.strip_prefix("members = [") {
+            Some(_) if line.contains(&quoted) => Some(line.to_string()),
+            Some(_) => Some(format!("{}, {quoted}]", line.strip_suffix(']')?)),
+            None => Some(line.to_string()),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    lines
+        .iter()
+        .any(|line| line.starts_with("members = ["))
+        .then(|| lines.join("\n") + "\n")
+}
+
+fn cargo_toml(year: u32, day: u32) -> String {
+    let aoc = if year == DEFAULT_YEAR {
+        "../aoc"
+    } else {
+        "../../aoc"
+    };
+    format!(
+        r#"[package]
+name = "{package}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc = {{ path = "{aoc}" }}
+"#,
+        package = package(year, day)
+    )
+}
+
+fn lib_rs(year: u32, day: u32, title: &str) -> String {
+    let (heading, year_const) = if year == DEFAULT_YEAR {
+        (String::new(), String::new())
+    } else {
+        (
+            format!(" {year}"),
+            format!("    const YEAR: u32 = {year};\n"),
+        )
+    };
+    format!(
+        r#"// Advent of Code{heading} - Day {day}: {title}
+
+use aoc::parse::ParseError;
+use aoc::solution::{{Answer, Solution, SolveError}};
+
+fn solve_part_one(_input: &str) -> Result<usize, SolveError> {{
+    Err(SolveError::Unsolved)
+}}
+
+fn solve_part_two(_input: &str) -> Result<usize, SolveError> {{
+    Err(SolveError::Unsolved)
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+{year_const}    const DAY: u32 = {day};
+    const TITLE: &'static str = "{escaped_title}";
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {{
+        Ok(input)
+    }}
+
+    fn part_one(input: &&str) -> Result<Answer, SolveError> {{
+        Ok(solve_part_one(input)?.into())
+    }}
+
+    fn part_two(input: &&str) -> Result<Answer, SolveError> {{
+        Ok(solve_part_two(input)?.into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    #[test]
+    #[ignore = "add the example from the puzzle"]
+    fn part1() {{
+        let example_input = "";
+        let answer = crate::solve_part_one(example_input).unwrap();
+        assert_eq!(answer, 0);
+    }}
+
+    #[test]
+    #[ignore = "add the example from the puzzle"]
+    fn part2() {{
+        let example_input = "";
+        let answer = crate::solve_part_two(example_input).unwrap();
+        assert_eq!(answer, 0);
+    }}
+}}
+"#,
+        escaped_title = title.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+fn main_rs(krate: &str, day: u32) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use {krate}::Day{day};
+
+fn main() -> ExitCode {{
+    aoc::solution::main::<Day{day}>()
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[dependencies]
+aoc = { path = "../aoc" }
+day-1 = { path = "../day-1" }
+day-2 = { path = "../day-2" }
+day-14 = { path = "../day-14" }
+
+[features]
+"#;
+
+    const WORKSPACE: &str = "[workspace]
+resolver = \"2\"
+
+members = [\"aoc\", \"day-*\", \"runner\"]
+";
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_14::Day14>(),
+];
+";
+
+    #[test]
+    fn test_key() {
+        assert_eq!(key("day-7", '-'), Some((DEFAULT_YEAR, 7)));
+        assert_eq!(key("day_5_2023", '_'), Some((2023, 5)));
+        assert_eq!(key("aoc", '-'), None);
+        assert_eq!(key("day-5-2023-x", '-'), None);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let key = |line: &str| {
+            let name = line.trim_start().strip_prefix("Day::of::<")?;
+            key(&name[..name.find("::")?], '_')
+        };
+        let days = insert_sorted(DAYS, (2024, 15), "    Day::of::<day_15::Day15>(),", key).unwrap();
+        assert!(days.ends_with("<day_14::Day14>(),\n    Day::of::<day_15::Day15>(),\n];\n"));
+        let days = insert_sorted(DAYS, (2024, 3), "    Day::of::<day_3::Day3>(),", key).unwrap();
+        assert!(
+            days.contains("<day_2::Day2>(),\n    Day::of::<day_3::Day3>(),\n    Day::of::<day_14")
+        );
+        let days =
+            insert_sorted(DAYS, (2023, 5), "    Day::of::<day_5_2023::Day5>(),", key).unwrap();
+        assert!(days.contains("&[\n    Day::of::<day_5_2023::Day5>(),\n    Day::of::<day_1::"));
+        assert!(insert_sorted("", (2024, 3), "", key).is_none());
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day-*\", \"runner\"]\n";
+        let added = add_member(manifest, "2023/day-*").unwrap();
+        assert_eq!(
+            added,
+            "[workspace]\nmembers = [\"aoc\", \"day-*\", \"runner\", \"2023/day-*\"]\n"
+        );
+        assert_eq!(add_member(&added, "2023/day-*").unwrap(), added);
+        assert!(add_member("[package]\n", "2023/day-*").is_none());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("runner").join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("runner").join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("runner").join("src").join("days.rs"), DAYS).unwrap();
+
+        let files = new_day(&root, 2024, 15, "Warehouse Woes").unwrap();
+        assert_eq!(files.len(), 5);
+        let lib = fs::read_to_string(root.join("day-15").join("src").join("lib.rs")).unwrap();
+        assert!(lib.starts_with("// Advent of Code - Day 15: Warehouse Woes\n"));
+        assert!(lib.contains("impl Solution for Day15 {\n    const DAY: u32 = 15;"));
+        let manifest = fs::read_to_string(root.join("runner").join("Cargo.toml")).unwrap();
+        assert!(manifest
+            .contains("day-14 = { path = \"../day-14\" }\nday-15 = { path = \"../day-15\" }\n"));
+
+        let files = new_day(&root, 2023, 5, "If You Give A Seed A Fertilizer").unwrap();
+        assert_eq!(files.len(), 6);
+        let dir = root.join("2023").join("day-5");
+        let lib = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day5 {\n    const YEAR: u32 = 2023;\n"));
+        let cargo = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"day-5-2023\""));
+        assert!(cargo.contains("aoc = { path = \"../../aoc\" }"));
+        let main = fs::read_to_string(dir.join("src").join("main.rs")).unwrap();
+        assert!(main.contains("use day_5_2023::Day5;"));
+        let manifest = fs::read_to_string(root.join("runner").join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "aoc = { path = \"../aoc\" }\nday-5-2023 = { path = \"../2023/day-5\" }\nday-1 ="
+        ));
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"2023/day-*\"]"));
+
+        assert!(new_day(&root, 2024, 15, "Warehouse Woes")
+            .unwrap_err()
+            .ends_with("already exists"));
+        assert!(new_day(&root, 2024, 26, "Too Late").is_err());
+        assert!(new_day(&root, 2014, 1, "Too Early").is_err());
+        // a year after the default one starts a new directory like any earlier year
+        new_day(&root, 2025, 1, "Secret Entrance").unwrap();
+        assert!(root
+            .join("2025")
+            .join("day-1")
+            .join("src")
+            .join("lib.rs")
+            .exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
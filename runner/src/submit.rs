@@ -0,0 +1,373 @@
+//! Answer submission: reading the verdict from the response page, and a local log of every
+//! submission that keeps us from sending answers that are known to be wrong, or sending any
+//! answer while the website asks us to wait.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::solution::{Answer, Part, DEFAULT_YEAR};
+
+pub const DEFAULT_LOG: &str = ".aoc/submissions.tsv";
+
+/// What the website made of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint in which direction
+    Wrong,
+    /// rate limited, the answer was not checked
+    Wait,
+    /// the part was solved before, the answer was not checked
+    AlreadySolved,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] = [
+        Verdict::Correct,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wrong,
+        Verdict::Wait,
+        Verdict::AlreadySolved,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::AlreadySolved => "already-solved",
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "not checked, submitted too recently",
+            Verdict::AlreadySolved => "not checked, already solved",
+        })
+    }
+}
+
+/// Verdict of a response page, and how long to wait before the next submission
+pub fn parse_response(page: &str) -> Option<(Verdict, Duration)> {
+    if page.contains("That's the right answer") {
+        return Some((Verdict::Correct, Duration::ZERO));
+    }
+    if page.contains("You don't seem to be solving the right level") {
+        return Some((Verdict::AlreadySolved, Duration::ZERO));
+    }
+    if page.contains("You gave an answer too recently") {
+        // "You have 1m 23s left to wait." or "You have 45s left to wait."
+        let wait = between(page, "You have ", " left to wait")
+            .and_then(parse_wait)
+            .unwrap_or(Duration::from_secs(60));
+        return Some((Verdict::Wait, wait));
+    }
+    if page.contains("That's not the right answer") {
+        let verdict = if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        // "Please wait one minute before trying again." or "wait 5 minutes before ..."
+        let wait = between(page, "lease wait ", " before trying again")
+            .and_then(|wait| match wait {
+                "one minute" => Some(Duration::from_secs(60)),
+                _ => wait
+                    .strip_suffix(" minutes")
+                    .and_then(|n| n.parse().ok())
+                    .map(|n: u64| Duration::from_secs(n * 60)),
+            })
+            .unwrap_or(Duration::from_secs(60));
+        return Some((verdict, wait));
+    }
+    None
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let rest = &text[text.find(start)? + start.len()..];
+    Some(&rest[..rest.find(end)?])
+}
+
+/// `1h 2m 3s`, with any of the units left out
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace()
+        .map(|part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// One submitted answer, as stored in the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// earliest time of the next submission, in seconds since the epoch
+    pub wait_until: u64,
+}
+
+impl Submission {
+    pub fn new(
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &Answer,
+        verdict: Verdict,
+        wait: Duration,
+    ) -> Self {
+        let timestamp = now();
+        Submission {
+            timestamp,
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            wait_until: timestamp + wait.as_secs(),
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part.number(),
+            self.answer,
+            self.verdict.name(),
+            self.wait_until
+        )
+    }
+
+    fn parse(line: &str) -> Option<Submission> {
+        let mut fields = line.split('\t').collect::<Vec<_>>();
+        // submissions without a year are of the default year
+        let year = match fields.len() {
+            7 => fields.remove(1).parse().ok()?,
+            _ => DEFAULT_YEAR,
+        };
+        let &[timestamp, day, part, answer, verdict, wait_until] = fields.as_slice() else {
+            return None;
+        };
+        Some(Submission {
+            timestamp: timestamp.parse().ok()?,
+            year,
+            day: day.parse().ok()?,
+            part: Part::from_number(part.parse().ok()?)?,
+            answer: answer.to_string(),
+            verdict: Verdict::ALL.into_iter().find(|v| v.name() == verdict)?,
+            wait_until: wait_until.parse().ok()?,
+        })
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// every submission in the log, none if the log does not exist
+pub fn load(path: &Path) -> Result<Vec<Submission>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                Submission::parse(line)
+                    .ok_or_else(|| format!("{}:{}: invalid submission", path.display(), i + 1))
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+    }
+}
+
+pub fn append(path: &Path, submission: &Submission) -> Result<(), String> {
+    let write = || -> io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", submission.to_line())
+    };
+    write().map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// Why an answer must not be submitted, judging by the earlier submissions
+pub fn check(
+    log: &[Submission],
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &Answer,
+    now: u64,
+) -> Result<(), String> {
+    if let Some(wait_until) = log.iter().map(|s| s.wait_until).max().filter(|&t| t > now) {
+        return Err(format!(
+            "submitted too recently, wait another {}s",
+            wait_until - now
+        ));
+    }
+    let earlier = log
+        .iter()
+        .filter(|s| (s.year, s.day, s.part) == (year, day, part));
+    let text = answer.to_string();
+    for submission in earlier {
+        if submission.verdict == Verdict::Correct {
+            return Err(format!(
+                "day {day} part {part} is already solved, with {}",
+                submission.answer
+            ));
+        }
+        if submission.verdict.is_wrong() && submission.answer == text {
+            return Err(format!(
+                "{text} was already submitted for day {day} part {part}, and was {}",
+                submission.verdict
+            ));
+        }
+        // answers beyond a known bound are wrong as well
+        let Answer::Number(n) = answer else { continue };
+        let Ok(bound) = submission.answer.parse::<i128>() else {
+            continue;
+        };
+        let beyond = match submission.verdict {
+            Verdict::TooHigh => *n > bound,
+            Verdict::TooLow => *n < bound,
+            _ => false,
+        };
+        if beyond {
+            return Err(format!(
+                "{text} is wrong, {bound} was already {}",
+                submission.verdict
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>.  Please wait one minute before trying again. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(CORRECT),
+            Some((Verdict::Correct, Duration::ZERO))
+        );
+        assert_eq!(
+            parse_response(TOO_LOW),
+            Some((Verdict::TooLow, Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse_response(WRONG),
+            Some((Verdict::Wrong, Duration::from_secs(300)))
+        );
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            Some((Verdict::Wait, Duration::from_secs(83)))
+        );
+        assert_eq!(
+            parse_response(SOLVED),
+            Some((Verdict::AlreadySolved, Duration::ZERO))
+        );
+        assert_eq!(parse_response("<html>maintenance</html>"), None);
+    }
+
+    fn submission(part: Part, answer: &str, verdict: Verdict, wait_until: u64) -> Submission {
+        Submission {
+            timestamp: 1000,
+            year: 2024,
+            day: 7,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            wait_until,
+        }
+    }
+
+    #[test]
+    fn test_line_roundtrip() {
+        let submission = submission(Part::Two, "11387", Verdict::TooHigh, 1060);
+        assert_eq!(
+            submission.to_line(),
+            "1000\t2024\t7\t2\t11387\ttoo-high\t1060"
+        );
+        assert_eq!(
+            Submission::parse(&submission.to_line()),
+            Some(submission.clone())
+        );
+        assert_eq!(
+            Submission::parse("1000\t7\t2\t11387\ttoo-high\t1060"),
+            Some(submission)
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let log = vec![
+            submission(Part::One, "3000", Verdict::TooLow, 1060),
+            submission(Part::One, "4000", Verdict::TooHigh, 1200),
+            submission(Part::Two, "abc", Verdict::Wrong, 1300),
+        ];
+        let number = |n: i128| Answer::Number(n);
+        assert!(check(&log, 2024, 7, Part::One, &number(3500), 1250)
+            .unwrap_err()
+            .starts_with("submitted too recently, wait another 50s"));
+        assert!(check(&log, 2024, 7, Part::One, &number(3500), 1300).is_ok());
+        assert!(check(&log, 2024, 7, Part::One, &number(3000), 1300).is_err());
+        assert!(check(&log, 2024, 7, Part::One, &number(2999), 1300).is_err());
+        assert!(check(&log, 2024, 7, Part::One, &number(4001), 1300).is_err());
+        assert!(check(&log, 2024, 7, Part::Two, &Answer::from("abc"), 1300).is_err());
+        assert!(check(&log, 2024, 7, Part::Two, &number(4001), 1300).is_ok());
+        assert!(check(&log, 2024, 8, Part::One, &number(3000), 1300).is_ok());
+        assert!(check(&log, 2023, 7, Part::One, &number(3000), 1300).is_ok());
+
+        let solved = vec![submission(Part::One, "3749", Verdict::Correct, 1000)];
+        assert_eq!(
+            check(&solved, 2024, 7, Part::One, &number(3749), 1300).unwrap_err(),
+            "day 7 part one is already solved, with 3749"
+        );
+    }
+}